@@ -0,0 +1,63 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use vector_operations::{add, scale, sub};
+
+/// The heap-allocating implementation `add` used before it was rewritten
+/// around `std::array::from_fn`, kept as a baseline for comparison.
+fn collect_add<const F: usize>(vec_a: &[f64; F], vec_b: &[f64; F]) -> [f64; F] {
+    vec_a
+        .iter()
+        .zip(vec_b.iter())
+        .map(|(a, b)| *a + *b)
+        .collect::<Vec<f64>>()
+        .try_into()
+        .unwrap()
+}
+
+fn input<const F: usize>(offset: f64) -> [f64; F] {
+    std::array::from_fn(|i| i as f64 + offset)
+}
+
+macro_rules! bench_sizes {
+    ($c:expr, $($size:literal),*) => {{
+        let mut group = $c.benchmark_group("add");
+        $(
+            let a = input::<$size>(0.0);
+            let b = input::<$size>(0.5);
+            group.bench_with_input(BenchmarkId::new("from_fn", $size), &$size, |bench, _| {
+                bench.iter(|| add(black_box(&a), black_box(&b)))
+            });
+            group.bench_with_input(BenchmarkId::new("collect", $size), &$size, |bench, _| {
+                bench.iter(|| collect_add(black_box(&a), black_box(&b)))
+            });
+        )*
+        group.finish();
+
+        let mut group = $c.benchmark_group("sub");
+        $(
+            let a = input::<$size>(0.0);
+            let b = input::<$size>(0.5);
+            group.bench_with_input(BenchmarkId::from_parameter($size), &$size, |bench, _| {
+                bench.iter(|| sub(black_box(&a), black_box(&b)))
+            });
+        )*
+        group.finish();
+
+        let mut group = $c.benchmark_group("scale");
+        $(
+            let a = input::<$size>(0.0);
+            group.bench_with_input(BenchmarkId::from_parameter($size), &$size, |bench, _| {
+                bench.iter(|| scale(black_box(&a), black_box(&1.5)))
+            });
+        )*
+        group.finish();
+    }};
+}
+
+fn elementwise(c: &mut Criterion) {
+    bench_sizes!(c, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024);
+}
+
+criterion_group!(benches, elementwise);
+criterion_main!(benches);
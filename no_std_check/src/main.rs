@@ -0,0 +1,65 @@
+//! Builds `vector_operations` with `default-features = false` into a
+//! `#![no_std]` binary. Linking fails with a duplicate `panic_impl` lang item
+//! if anything in the crate pulls in `std`.
+//!
+//! Run with `cargo run` from this directory; it exits with status zero.
+//! `cargo run --features libm` also checks the floating point norms and
+//! decompositions, which need `libm` without `std`.
+
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+use core::panic::PanicInfo;
+
+use vector_operations::{add, matrix_multiply, matrix_vec_multiply, scale, sub, Matrix, Vector};
+
+// The C runtime provides `_start`, `memset` and friends for the host target.
+#[link(name = "c")]
+extern "C" {}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
+
+// The prebuilt `core` references the unwinding personality even with
+// `panic = "abort"`; it is never called.
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+#[no_mangle]
+pub extern "C" fn main(_argc: i32, _argv: *const *const u8) -> i32 {
+    let a = black_box([1, 2, 3]);
+    let b = black_box([3, 2, 1]);
+    let c = scale(&add(&a, &sub(&a, &b)), &2);
+    let matrix = black_box([[1, 0, 0], [0, 1, 0]]);
+    let d = matrix_vec_multiply(&matrix, &c);
+    let e = matrix_multiply(&matrix, &[[1, 0], [0, 1], [0, 0]]);
+    let f = Matrix::from_rows(e) * (Vector::new(d) - Vector::new([1, 1]));
+    ((f != Vector::new([-3, 3])) || !floats()) as i32
+}
+
+#[cfg(feature = "libm")]
+fn floats() -> bool {
+    use vector_operations::{norm_l2, Cholesky, Lu, Svd, SymmetricEigen};
+
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+    let matrix = black_box([[4.0, 2.0], [2.0, 5.0]]);
+    let solution = Lu::new(&matrix).solve(&[6.0, 7.0]);
+    let cholesky = Cholesky::new(&matrix).map(|cholesky| cholesky.solve(&[6.0, 7.0]));
+    // The eigenvalues and singular values are 1 and 8.
+    let matrix = black_box([[4.5, 3.5], [3.5, 4.5]]);
+    let eigenvalues = SymmetricEigen::new(&matrix).map(|eigen| eigen.eigenvalues());
+    let singular_values = Svd::new(&matrix).map(|svd| [svd.singular_values()[0], svd.singular_values()[1]]);
+    norm_l2(&black_box([3.0_f64, -4.0])) == 5.0
+        && solution == Ok([1.0, 1.0])
+        && cholesky == Ok([1.0, 1.0])
+        && eigenvalues.is_ok_and(|[small, large]| close(small, 1.0) && close(large, 8.0))
+        && singular_values.is_ok_and(|[large, small]| close(large, 8.0) && close(small, 1.0))
+}
+
+#[cfg(not(feature = "libm"))]
+fn floats() -> bool {
+    true
+}
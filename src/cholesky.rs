@@ -0,0 +1,554 @@
+use crate::{ComplexField, One, RealField, VectorError, Zero};
+
+/// Cholesky Decomposition
+///
+/// The factorization `A = L Lᴴ` of a Hermitian positive-definite matrix,
+/// where `L` is lower triangular with a positive real diagonal and `Lᴴ` is
+/// its conjugate transpose. For a real matrix this is `A = L Lᵀ` of a
+/// symmetric matrix. Only the lower triangle of the input is read.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{Cholesky, VectorError};
+///
+/// let cholesky = Cholesky::new(&[[4.0, 2.0], [2.0, 5.0]]).unwrap();
+/// assert_eq!(cholesky.l(), [[2.0, 0.0], [1.0, 2.0]]);
+/// assert_eq!(cholesky.solve(&[6.0, 7.0]), [1.0, 1.0]);
+/// assert_eq!(Cholesky::new(&[[1.0, 2.0], [2.0, 1.0]]), Err(VectorError::NotPositiveDefinite));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    l: [[T; N]; N],
+}
+
+impl<T: ComplexField, const N: usize> Cholesky<T, N> {
+    /// Factor `matrix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if a diagonal element of
+    /// `L` would be the square root of a value that is not positive.
+    pub fn new(matrix: &[[T; N]; N]) -> Result<Self, VectorError> {
+        let mut l = *matrix;
+        cholesky_in_place(l.as_flattened_mut(), N)?;
+        Ok(Self { l })
+    }
+
+    /// The lower triangular factor `L`.
+    pub fn l(&self) -> [[T; N]; N] {
+        self.l
+    }
+
+    /// Solve `A x = b`.
+    pub fn solve(&self, b: &[T; N]) -> [T; N] {
+        let mut x = *b;
+        cholesky_solve(self.l.as_flattened(), &mut x, N, 1);
+        x
+    }
+
+    /// Solve `A X = B` for every column of `B` at once.
+    pub fn solve_many<const K: usize>(&self, b: &[[T; K]; N]) -> [[T; K]; N] {
+        let mut x = *b;
+        cholesky_solve(self.l.as_flattened(), x.as_flattened_mut(), N, K);
+        x
+    }
+
+    /// The inverse of the matrix.
+    pub fn inverse(&self) -> [[T; N]; N] {
+        self.solve_many(&crate::identity())
+    }
+
+    /// The determinant of the matrix: the square of the product of the
+    /// diagonal of `L`.
+    pub fn determinant(&self) -> T {
+        let product = (0..N).fold(T::one(), |product, i| product * self.l[i][i]);
+        product * product
+    }
+
+    /// The natural logarithm of the determinant, which stays finite when the
+    /// determinant itself would overflow or underflow.
+    pub fn log_determinant(&self) -> T::Real {
+        let sum = (0..N).fold(T::Real::zero(), |sum, i| sum + self.l[i][i].real().ln());
+        sum + sum
+    }
+
+    /// Update the factorization to that of `A + x xᴴ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if a diagonal element of
+    /// `L` would not be finite, which happens when `x` is not, leaving the
+    /// factorization unchanged.
+    pub fn rank_one_update(&mut self, x: &[T; N]) -> Result<(), VectorError> {
+        let mut l = self.l;
+        let mut x = *x;
+        rank_one_in_place(l.as_flattened_mut(), &mut x, N, false)?;
+        self.l = l;
+        Ok(())
+    }
+
+    /// Update the factorization to that of `A - x xᴴ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if `A - x xᴴ` is not
+    /// positive-definite, leaving the factorization unchanged.
+    pub fn rank_one_downdate(&mut self, x: &[T; N]) -> Result<(), VectorError> {
+        let mut l = self.l;
+        let mut x = *x;
+        rank_one_in_place(l.as_flattened_mut(), &mut x, N, true)?;
+        self.l = l;
+        Ok(())
+    }
+}
+
+/// LDLᴴ Decomposition
+///
+/// The factorization `A = L D Lᴴ` of a Hermitian positive semidefinite
+/// matrix, where `L` is unit lower triangular and `D` is real, diagonal and
+/// nonnegative. Unlike [`Cholesky`] it takes no square roots and accepts
+/// singular matrices. Only the lower triangle of the input is read.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Ldlt;
+///
+/// let ldlt = Ldlt::new(&[[4.0, 2.0], [2.0, 1.0]]).unwrap();
+/// assert_eq!(ldlt.d(), [4.0, 0.0]);
+/// assert_eq!(ldlt.determinant(), 0.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ldlt<T, const N: usize> {
+    l: [[T; N]; N],
+    d: [T; N],
+}
+
+impl<T: ComplexField, const N: usize> Ldlt<T, N> {
+    /// Factor `matrix`. Diagonal elements of `D` that are zero relative to
+    /// the largest element of the matrix are set to exactly zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if an element of `D` is
+    /// negative, or zero with a nonzero column of `L` below it, so the matrix
+    /// is not even positive semidefinite.
+    pub fn new(matrix: &[[T; N]; N]) -> Result<Self, VectorError> {
+        let mut l = *matrix;
+        let mut d = [T::zero(); N];
+        ldlt_in_place(l.as_flattened_mut(), &mut d, N)?;
+        Ok(Self { l, d })
+    }
+
+    /// The unit lower triangular factor `L`.
+    pub fn l(&self) -> [[T; N]; N] {
+        self.l
+    }
+
+    /// The diagonal of `D`.
+    pub fn d(&self) -> [T; N] {
+        self.d
+    }
+
+    /// The determinant of the matrix: the product of the diagonal of `D`.
+    pub fn determinant(&self) -> T {
+        self.d.iter().fold(T::one(), |product, d| product * *d)
+    }
+
+    /// The natural logarithm of the determinant, which stays finite when the
+    /// determinant itself would overflow or underflow. It is negative
+    /// infinity for a singular matrix.
+    pub fn log_determinant(&self) -> T::Real {
+        self.d.iter().fold(T::Real::zero(), |sum, d| sum + d.real().ln())
+    }
+
+    /// Update the factorization to that of `A + x xᴴ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if an element of `D`
+    /// would not be finite, which happens when `x` is not, leaving the
+    /// factorization unchanged.
+    pub fn rank_one_update(&mut self, x: &[T; N]) -> Result<(), VectorError> {
+        self.rank_one(x, false)
+    }
+
+    /// Update the factorization to that of `A - x xᴴ`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if an element of `D`
+    /// would become zero or negative, leaving the factorization unchanged.
+    pub fn rank_one_downdate(&mut self, x: &[T; N]) -> Result<(), VectorError> {
+        self.rank_one(x, true)
+    }
+
+    /// Solve `A x = b`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if an element of `D` is zero.
+    pub fn solve(&self, b: &[T; N]) -> Result<[T; N], VectorError> {
+        self.check_singular()?;
+        let mut x = *b;
+        ldlt_solve(self.l.as_flattened(), &self.d, &mut x, N, 1);
+        Ok(x)
+    }
+
+    /// Solve `A X = B` for every column of `B` at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if an element of `D` is zero.
+    pub fn solve_many<const K: usize>(&self, b: &[[T; K]; N]) -> Result<[[T; K]; N], VectorError> {
+        self.check_singular()?;
+        let mut x = *b;
+        ldlt_solve(self.l.as_flattened(), &self.d, x.as_flattened_mut(), N, K);
+        Ok(x)
+    }
+
+    /// The inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if an element of `D` is zero.
+    pub fn inverse(&self) -> Result<[[T; N]; N], VectorError> {
+        self.solve_many(&crate::identity())
+    }
+
+    fn rank_one(&mut self, x: &[T; N], downdate: bool) -> Result<(), VectorError> {
+        let (mut l, mut d) = (self.l, self.d);
+        let mut x = *x;
+        ldlt_rank_one_in_place(l.as_flattened_mut(), &mut d, &mut x, N, downdate)?;
+        self.l = l;
+        self.d = d;
+        Ok(())
+    }
+
+    fn check_singular(&self) -> Result<(), VectorError> {
+        if self.d.iter().any(|d| d.is_zero()) {
+            Err(VectorError::Singular)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Overwrite the `n` by `n` row-major matrix in `a` with its Cholesky factor,
+/// zeroing the strict upper triangle.
+pub(crate) fn cholesky_in_place<T: ComplexField>(a: &mut [T], n: usize) -> Result<(), VectorError> {
+    for j in 0..n {
+        let diagonal = (0..j).fold(a[j * n + j].real(), |sum, k| sum - a[j * n + k].norm_sqr());
+        if diagonal <= T::Real::zero() || !diagonal.is_finite() {
+            return Err(VectorError::NotPositiveDefinite);
+        }
+        let diagonal = T::from_real(diagonal.sqrt());
+        a[j * n + j] = diagonal;
+        for i in j + 1..n {
+            let sum = (0..j).fold(a[i * n + j], |sum, k| sum - a[i * n + k] * a[j * n + k].conj());
+            a[i * n + j] = sum / diagonal;
+            a[j * n + i] = T::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Solve `L Lᴴ X = B` for the `n` by `k` row-major right-hand side in `x`.
+pub(crate) fn cholesky_solve<T: ComplexField>(l: &[T], x: &mut [T], n: usize, k: usize) {
+    for i in 0..n {
+        for j in 0..i {
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= l[i * n + j] * value;
+            }
+        }
+        let pivot = l[i * n + i].recip();
+        for value in &mut x[i * k..i * k + k] {
+            *value *= pivot;
+        }
+    }
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= l[j * n + i].conj() * value;
+            }
+        }
+        let pivot = l[i * n + i].recip();
+        for value in &mut x[i * k..i * k + k] {
+            *value *= pivot;
+        }
+    }
+}
+
+/// Apply a rank-one update, or a downdate if `downdate` is set, to the
+/// Cholesky factor in `l`, consuming `x`.
+pub(crate) fn rank_one_in_place<T: ComplexField>(l: &mut [T], x: &mut [T], n: usize, downdate: bool) -> Result<(), VectorError> {
+    for k in 0..n {
+        let diagonal = l[k * n + k].real();
+        let squared = if downdate {
+            diagonal * diagonal - x[k].norm_sqr()
+        } else {
+            diagonal * diagonal + x[k].norm_sqr()
+        };
+        if squared <= T::Real::zero() || !squared.is_finite() {
+            return Err(VectorError::NotPositiveDefinite);
+        }
+        let r = squared.sqrt();
+        let c = T::from_real(r / diagonal);
+        let s = x[k] / T::from_real(diagonal);
+        l[k * n + k] = T::from_real(r);
+        for i in k + 1..n {
+            let updated = if downdate {
+                (l[i * n + k] - s.conj() * x[i]) / c
+            } else {
+                (l[i * n + k] + s.conj() * x[i]) / c
+            };
+            l[i * n + k] = updated;
+            x[i] = c * x[i] - s * updated;
+        }
+    }
+    Ok(())
+}
+
+/// Overwrite the `n` by `n` row-major matrix in `a` with the unit lower
+/// triangular factor of its LDLᴴ decomposition, writing the diagonal to `d`.
+pub(crate) fn ldlt_in_place<T: ComplexField>(a: &mut [T], d: &mut [T], n: usize) -> Result<(), VectorError> {
+    let scale = (0..n).fold(T::Real::zero(), |max, i| (0..=i).fold(max, |max, j| max.max(a[i * n + j].modulus())));
+    let tolerance = scale * T::Real::epsilon() * T::Real::from_f64(n as f64);
+    for j in 0..n {
+        let pivot = (0..j).fold(a[j * n + j].real(), |sum, k| sum - a[j * n + k].norm_sqr() * d[k].real());
+        if pivot < -tolerance || !pivot.is_finite() {
+            return Err(VectorError::NotPositiveDefinite);
+        }
+        let singular = pivot <= tolerance;
+        d[j] = if singular { T::zero() } else { T::from_real(pivot) };
+        a[j * n + j] = T::one();
+        for i in j + 1..n {
+            let sum = (0..j).fold(a[i * n + j], |sum, k| sum - a[i * n + k] * a[j * n + k].conj() * d[k]);
+            if singular {
+                // In a semidefinite matrix a zero pivot has a zero column
+                // below it; anything else means the matrix is indefinite.
+                if sum.modulus() > tolerance {
+                    return Err(VectorError::NotPositiveDefinite);
+                }
+                a[i * n + j] = T::zero();
+            } else {
+                a[i * n + j] = sum / d[j];
+            }
+            a[j * n + i] = T::zero();
+        }
+    }
+    Ok(())
+}
+
+/// Apply a rank-one update, or a downdate if `downdate` is set, to the LDLᴴ
+/// factors in `l` and `d`, consuming `x`. Columns with a zero pivot that `x`
+/// does not touch are left as they are.
+pub(crate) fn ldlt_rank_one_in_place<T: ComplexField>(
+    l: &mut [T],
+    d: &mut [T],
+    x: &mut [T],
+    n: usize,
+    downdate: bool,
+) -> Result<(), VectorError> {
+    // The weight of the remaining rank-one term, which starts as ±1.
+    let mut alpha = if downdate { -T::Real::one() } else { T::Real::one() };
+    for j in 0..n {
+        let p = x[j];
+        let old = d[j].real();
+        if old.is_zero() && p.is_zero() {
+            continue;
+        }
+        let new = old + alpha * p.norm_sqr();
+        if new <= T::Real::zero() || !new.is_finite() {
+            return Err(VectorError::NotPositiveDefinite);
+        }
+        let beta = p.conj() * T::from_real(alpha / new);
+        alpha = alpha * old / new;
+        d[j] = T::from_real(new);
+        for i in j + 1..n {
+            x[i] -= p * l[i * n + j];
+            l[i * n + j] += beta * x[i];
+        }
+    }
+    Ok(())
+}
+
+/// Solve `L D Lᴴ X = B` for the `n` by `k` row-major right-hand side in `x`.
+pub(crate) fn ldlt_solve<T: ComplexField>(l: &[T], d: &[T], x: &mut [T], n: usize, k: usize) {
+    for i in 0..n {
+        for j in 0..i {
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= l[i * n + j] * value;
+            }
+        }
+    }
+    for (row, d) in x.chunks_exact_mut(k).zip(d) {
+        let pivot = d.recip();
+        for value in row {
+            *value *= pivot;
+        }
+    }
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= l[j * n + i].conj() * value;
+            }
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{identity, matrix_add, matrix_multiply, matrix_sub, matrix_vec_multiply, transpose};
+
+    fn assert_close<const M: usize, const N: usize>(a: &[[f64; N]; M], b: &[[f64; N]; M]) {
+        for (x, y) in a.as_flattened().iter().zip(b.as_flattened()) {
+            assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+
+    fn outer<const N: usize>(x: &[f64; N]) -> [[f64; N]; N] {
+        crate::from_fn(|i, j| x[i] * x[j])
+    }
+
+    const SPD: [[f64; 3]; 3] = [[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+
+    #[test]
+    fn test_cholesky() {
+        let cholesky = Cholesky::new(&SPD).unwrap();
+        assert_eq!(cholesky.l(), [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        assert_close(&matrix_multiply(&cholesky.l(), &transpose(&cholesky.l())), &SPD);
+        let x = cholesky.solve(&[1.0, 2.0, 3.0]);
+        assert_close(&[matrix_vec_multiply(&SPD, &x)], &[[1.0, 2.0, 3.0]]);
+        assert_close(&matrix_multiply(&SPD, &cholesky.inverse()), &identity());
+        assert!((cholesky.determinant() - 36.0).abs() < 1e-9);
+        assert!((cholesky.log_determinant() - 36.0_f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        assert_eq!(Cholesky::new(&[[1.0, 2.0], [2.0, 1.0]]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(Cholesky::new(&[[1.0, 1.0], [1.0, 1.0]]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(Cholesky::new(&[[-1.0_f32]]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(Ldlt::new(&[[1.0, 2.0], [2.0, 1.0]]), Err(VectorError::NotPositiveDefinite));
+    }
+
+    #[test]
+    fn test_rank_one_update_and_downdate() {
+        let x = [1.0, -2.0, 0.5];
+        let mut cholesky = Cholesky::new(&SPD).unwrap();
+        cholesky.rank_one_update(&x).unwrap();
+        let updated = matrix_add(&SPD, &outer(&x));
+        assert_close(&cholesky.l(), &Cholesky::new(&updated).unwrap().l());
+        cholesky.rank_one_downdate(&x).unwrap();
+        assert_close(&cholesky.l(), &Cholesky::new(&SPD).unwrap().l());
+        let before = cholesky;
+        assert_eq!(cholesky.rank_one_downdate(&[3.0, 0.0, 0.0]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(cholesky, before);
+        let downdated = matrix_sub(&SPD, &outer(&[1.0, 3.0, -4.0]));
+        cholesky.rank_one_downdate(&[1.0, 3.0, -4.0]).unwrap();
+        assert_close(&matrix_multiply(&cholesky.l(), &transpose(&cholesky.l())), &downdated);
+        let before = cholesky;
+        assert_eq!(cholesky.rank_one_update(&[1.0, f64::NAN, 0.0]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(cholesky, before);
+    }
+
+    #[test]
+    fn test_ldlt() {
+        let ldlt = Ldlt::new(&SPD).unwrap();
+        let d = crate::diagonal(&ldlt.d());
+        assert_close(&matrix_multiply(&matrix_multiply(&ldlt.l(), &d), &transpose(&ldlt.l())), &SPD);
+        assert!((ldlt.determinant() - 36.0).abs() < 1e-9);
+        let x = ldlt.solve(&[1.0, 2.0, 3.0]).unwrap();
+        assert_close(&[matrix_vec_multiply(&SPD, &x)], &[[1.0, 2.0, 3.0]]);
+        // Positive semidefinite with rank two.
+        let semidefinite = [[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 2.0]];
+        let ldlt = Ldlt::new(&semidefinite).unwrap();
+        assert_eq!(ldlt.d(), [1.0, 0.0, 2.0]);
+        let d = crate::diagonal(&ldlt.d());
+        assert_close(&matrix_multiply(&matrix_multiply(&ldlt.l(), &d), &transpose(&ldlt.l())), &semidefinite);
+        assert_eq!(ldlt.solve(&[1.0, 1.0, 1.0]), Err(VectorError::Singular));
+        assert_eq!(ldlt.log_determinant(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ldlt_indefinite() {
+        // A zero pivot with a nonzero column below it cannot be semidefinite.
+        assert_eq!(Ldlt::new(&[[0.0, 1.0], [1.0, 0.0]]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(Ldlt::new(&[[0.0, 1.0], [1.0, 5.0]]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(Ldlt::new(&[[1.0, 1.0, 1.0], [1.0, 1.0, 2.0], [1.0, 2.0, 6.0]]), Err(VectorError::NotPositiveDefinite));
+        let zero = Ldlt::new(&[[0.0, 0.0], [0.0, 3.0]]).unwrap();
+        let d = crate::diagonal(&zero.d());
+        assert_close(&matrix_multiply(&matrix_multiply(&zero.l(), &d), &transpose(&zero.l())), &[[0.0, 0.0], [0.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_ldlt_rank_one_update_and_downdate() {
+        let x = [1.0, -2.0, 0.5];
+        let rebuild = |ldlt: &Ldlt<f64, 3>| matrix_multiply(&matrix_multiply(&ldlt.l(), &crate::diagonal(&ldlt.d())), &transpose(&ldlt.l()));
+        let mut ldlt = Ldlt::new(&SPD).unwrap();
+        assert!((ldlt.log_determinant() - 36.0_f64.ln()).abs() < 1e-12);
+        ldlt.rank_one_update(&x).unwrap();
+        assert_close(&rebuild(&ldlt), &matrix_add(&SPD, &outer(&x)));
+        ldlt.rank_one_downdate(&x).unwrap();
+        assert_close(&rebuild(&ldlt), &SPD);
+        let before = ldlt;
+        assert_eq!(ldlt.rank_one_downdate(&[3.0, 0.0, 0.0]), Err(VectorError::NotPositiveDefinite));
+        assert_eq!(ldlt, before);
+        // Updating a semidefinite factorization along its null space.
+        let mut semidefinite = Ldlt::new(&[[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 2.0]]).unwrap();
+        semidefinite.rank_one_update(&[0.0, 1.0, 1.0]).unwrap();
+        assert_close(&rebuild(&semidefinite), &[[1.0, 1.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_hermitian() {
+        use crate::{conjugate_transpose, Complex};
+        let c = Complex::new;
+        let l = [[c(2.0, 0.0), c(0.0, 0.0), c(0.0, 0.0)], [c(1.0, -1.0), c(3.0, 0.0), c(0.0, 0.0)], [c(0.0, 2.0), c(-1.0, 1.0), c(1.0, 0.0)]];
+        let matrix = matrix_multiply(&l, &conjugate_transpose(&l));
+        let cholesky = Cholesky::new(&matrix).unwrap();
+        assert_eq!(cholesky.l(), l);
+        assert_eq!(cholesky.determinant(), c(36.0, 0.0));
+        let b = [c(1.0, 0.0), c(0.0, 1.0), c(2.0, -1.0)];
+        for (x, y) in matrix_vec_multiply(&matrix, &cholesky.solve(&b)).iter().zip(&b) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        let x = [c(0.5, 1.0), c(0.0, -2.0), c(1.0, 1.0)];
+        let mut updated = cholesky;
+        updated.rank_one_update(&x).unwrap();
+        let product = matrix_add(&matrix, &matrix_multiply(&transpose(&[x]), &conjugate_transpose(&transpose(&[x]))));
+        for (x, y) in matrix_multiply(&updated.l(), &conjugate_transpose(&updated.l())).as_flattened().iter().zip(product.as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-10);
+        }
+        updated.rank_one_downdate(&x).unwrap();
+        for (x, y) in updated.l().as_flattened().iter().zip(l.as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-10);
+        }
+        let ldlt = Ldlt::new(&matrix).unwrap();
+        assert!((ldlt.determinant() - c(36.0, 0.0)).modulus() < 1e-10);
+        for (x, y) in matrix_vec_multiply(&matrix, &ldlt.solve(&b).unwrap()).iter().zip(&b) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+    }
+}
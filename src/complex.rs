@@ -0,0 +1,283 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Abs, ComplexField, Elimination, Field, One, RealField, Ring, Zero};
+
+/// Complex Number
+///
+/// A number `re + im i` with real and imaginary parts of type `T`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Complex;
+///
+/// let a = Complex::new(1.0, 2.0);
+/// let b = Complex::new(3.0, -1.0);
+/// assert_eq!(a * b, Complex::new(5.0, 5.0));
+/// assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+/// assert_eq!(Complex::new(3.0, 4.0).modulus(), 5.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the real and imaginary parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Create a complex number from its real and imaginary parts.
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Ring> Complex<T> {
+    /// Create a complex number with a zero imaginary part.
+    pub fn from_real(re: T) -> Self {
+        Self { re, im: T::zero() }
+    }
+
+    /// The imaginary unit `i`.
+    pub fn i() -> Self {
+        Self { re: T::zero(), im: T::one() }
+    }
+
+    /// The square of the modulus, `re² + im²`.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Ring + Neg<Output = T>> Complex<T> {
+    /// The complex conjugate `re - im i`.
+    pub fn conj(&self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+}
+
+impl<T: RealField> Complex<T> {
+    /// The modulus `|z|`, computed without overflow for large parts.
+    pub fn modulus(&self) -> T {
+        let (re, im) = (self.re.abs(), self.im.abs());
+        let (large, small) = if re > im { (re, im) } else { (im, re) };
+        if large.is_zero() {
+            return large;
+        }
+        let ratio = small / large;
+        large * (T::one() + ratio * ratio).sqrt()
+    }
+
+    /// The argument, the angle from the positive real axis in `(-π, π]`.
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+}
+
+impl<T: Ring> Zero for Complex<T> {
+    fn zero() -> Self {
+        Self { re: T::zero(), im: T::zero() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Ring> One for Complex<T> {
+    fn one() -> Self {
+        Self::from_real(T::one())
+    }
+}
+
+impl<T: Field + PartialOrd + Abs> Field for Complex<T> {}
+
+impl<T: Field + PartialOrd + Abs> Elimination for Complex<T> {
+    fn eliminate(a: &mut [Self], n: usize) -> Self {
+        // The 1-norm orders pivots as well as the modulus without a root.
+        crate::lu::lu_determinant(a, n, |z| z.re.abs() + z.im.abs())
+    }
+}
+
+impl<T: RealField> ComplexField for Complex<T> {
+    type Real = T;
+
+    fn conj(self) -> Self {
+        Complex::conj(&self)
+    }
+
+    fn real(self) -> T {
+        self.re
+    }
+
+    fn modulus(self) -> T {
+        Complex::modulus(&self)
+    }
+
+    fn norm_sqr(self) -> T {
+        Complex::norm_sqr(&self)
+    }
+
+    fn from_real(value: T) -> Self {
+        Complex::from_real(value)
+    }
+}
+
+impl<T: Ring> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::from_real(re)
+    }
+}
+
+impl<T: Ring> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl<T: Ring> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Ring> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl<T: Ring> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Ring> Mul for Complex<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+impl<T: Ring> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Division with Smith's algorithm, which divides through by the larger part
+/// of `rhs` instead of forming `|rhs|²`, so quotients of very large or very
+/// small numbers do not overflow or underflow.
+impl<T: Field + PartialOrd + Abs> Div for Complex<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let ratio = rhs.im / rhs.re;
+            let denominator = rhs.re + rhs.im * ratio;
+            Self {
+                re: (self.re + self.im * ratio) / denominator,
+                im: (self.im - self.re * ratio) / denominator,
+            }
+        } else {
+            let ratio = rhs.re / rhs.im;
+            let denominator = rhs.re * ratio + rhs.im;
+            Self {
+                re: (self.re * ratio + self.im) / denominator,
+                im: (self.im * ratio - self.re) / denominator,
+            }
+        }
+    }
+}
+
+impl<T: Field + PartialOrd + Abs> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Ring + Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { re: -self.re, im: -self.im }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1, 2);
+        let b = Complex::new(3, -1);
+        assert_eq!(a + b, Complex::new(4, 1));
+        assert_eq!(a - b, Complex::new(-2, 3));
+        assert_eq!(a * b, Complex::new(5, 5));
+        assert_eq!(Complex::<i32>::i() * Complex::i(), Complex::from_real(-1));
+        assert_eq!(-a, Complex::new(-1, -2));
+        assert_eq!(a * a.conj(), Complex::from_real(a.norm_sqr()));
+        assert_eq!(Complex::new(0.0, 10.0) / Complex::new(4.0, 2.0), Complex::new(1.0, 2.0));
+        assert_eq!(Complex::new(-6.0, 8.0) / Complex::new(2.0, 4.0), Complex::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_division_extremes() {
+        let huge = Complex::new(1e300, 1e300);
+        assert_eq!(huge / huge, Complex::from_real(1.0));
+        let tiny = Complex::new(1e-300, -1e-300);
+        assert_eq!(tiny / tiny, Complex::from_real(1.0));
+        let quotient = Complex::new(1.0, 0.0) / Complex::new(0.0, 1e-300);
+        assert!(quotient.re == 0.0 && (quotient.im / 1e300 + 1.0).abs() < 1e-15);
+        let quotient = Complex::new(1.0, 1.0) / huge;
+        assert!((quotient.re * 1e300 - 1.0).abs() < 1e-15 && quotient.im == 0.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_polar() {
+        assert_eq!(Complex::new(-3.0, 4.0).modulus(), 5.0);
+        assert_eq!(Complex::new(1e300, 1e300).modulus(), 1e300 * 2.0_f64.sqrt());
+        assert_eq!(Complex::new(0.0, 0.0).modulus(), 0.0);
+        assert_eq!(Complex::new(0.0, 2.0).arg(), core::f64::consts::FRAC_PI_2);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_vector_operations() {
+        use crate::{add, conjugate_transpose, inner_product, matrix_multiply, matrix_vec_multiply, scale, sub, Matrix};
+        let i = Complex::<f64>::i();
+        let one = Complex::from_real(1.0);
+        let a = [one, i];
+        let b = [i, Complex::new(2.0, -1.0)];
+        assert_eq!(add(&a, &b), [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]);
+        assert_eq!(sub(&a, &b), [Complex::new(1.0, -1.0), Complex::new(-2.0, 2.0)]);
+        assert_eq!(scale(&a, &i), [i, -one]);
+        assert_eq!(inner_product(&a, &b), Complex::new(-1.0, -1.0));
+        assert_eq!(inner_product(&b, &a), Complex::new(-1.0, 1.0));
+        assert_eq!(inner_product(&b, &b), Complex::from_real(6.0));
+        let matrix = [[one, i], [-i, Complex::from_real(2.0)]];
+        assert_eq!(matrix_vec_multiply(&matrix, &a), [Complex::from_real(0.0), Complex::new(0.0, 1.0)]);
+        // The matrix is Hermitian, so it equals its conjugate transpose.
+        assert_eq!(conjugate_transpose(&matrix), matrix);
+        let product = matrix_multiply(&conjugate_transpose(&[a]), &[a]);
+        assert_eq!(product, [[one, i], [-i, one]]);
+        assert_eq!(Matrix::from([b]).conjugate_transpose(), Matrix::from([[-i], [Complex::new(2.0, 1.0)]]));
+    }
+}
@@ -0,0 +1,174 @@
+use core::ops::Neg;
+
+use crate::{dot, Ring};
+
+/// Cross Product
+///
+/// The vector perpendicular to two 3D vectors, following the right-hand
+/// rule, whose length is the area of the parallelogram they span.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::cross;
+///
+/// let x = [1, 0, 0];
+/// let y = [0, 1, 0];
+/// assert_eq!(cross(&x, &y), [0, 0, 1]);
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// A new vector containing the cross product of the two input vectors.
+pub fn cross<T: Ring>(vec_a: &[T; 3], vec_b: &[T; 3]) -> [T; 3] {
+    [
+        vec_a[1] * vec_b[2] - vec_a[2] * vec_b[1],
+        vec_a[2] * vec_b[0] - vec_a[0] * vec_b[2],
+        vec_a[0] * vec_b[1] - vec_a[1] * vec_b[0],
+    ]
+}
+
+/// Perp-Dot Product
+///
+/// The 2D analogue of the cross product: the `z` component of the cross
+/// product of the two vectors extended with a zero `z`. It is positive when
+/// `vec_b` is counter-clockwise from `vec_a`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::perp_dot;
+///
+/// assert_eq!(perp_dot(&[1, 0], &[0, 1]), 1);
+/// assert_eq!(perp_dot(&[0, 1], &[1, 0]), -1);
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The signed area of the parallelogram spanned by the two vectors.
+pub fn perp_dot<T: Ring>(vec_a: &[T; 2], vec_b: &[T; 2]) -> T {
+    vec_a[0] * vec_b[1] - vec_a[1] * vec_b[0]
+}
+
+/// Perpendicular Vector
+///
+/// Rotate a 2D vector a quarter turn counter-clockwise.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::perpendicular;
+///
+/// assert_eq!(perpendicular(&[2, 1]), [-1, 2]);
+/// ```
+///
+/// # Arguments
+///
+/// - `vec`: The vector to rotate.
+///
+/// # Returns
+///
+/// A new vector of the same length perpendicular to the input.
+pub fn perpendicular<T: Ring + Neg<Output = T>>(vec: &[T; 2]) -> [T; 2] {
+    [-vec[1], vec[0]]
+}
+
+/// Scalar Triple Product
+///
+/// The dot product of `vec_a` with the cross product of `vec_b` and `vec_c`,
+/// which is the signed volume of the parallelepiped they span.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::scalar_triple_product;
+///
+/// let a = [1, 0, 0];
+/// let b = [0, 2, 0];
+/// let c = [0, 0, 3];
+/// assert_eq!(scalar_triple_product(&a, &b, &c), 6);
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+/// - `vec_c`: The third vector.
+///
+/// # Returns
+///
+/// The value of `a · (b × c)`.
+pub fn scalar_triple_product<T: Ring>(vec_a: &[T; 3], vec_b: &[T; 3], vec_c: &[T; 3]) -> T {
+    dot(vec_a, &cross(vec_b, vec_c))
+}
+
+/// Vector Triple Product
+///
+/// The cross product of `vec_a` with the cross product of `vec_b` and
+/// `vec_c`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::vector_triple_product;
+///
+/// let a = [1, 0, 0];
+/// let b = [1, 1, 0];
+/// let c = [0, 0, 1];
+/// assert_eq!(vector_triple_product(&a, &b, &c), [0, 0, -1]);
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+/// - `vec_c`: The third vector.
+///
+/// # Returns
+///
+/// A new vector containing `a × (b × c)`.
+pub fn vector_triple_product<T: Ring>(vec_a: &[T; 3], vec_b: &[T; 3], vec_c: &[T; 3]) -> [T; 3] {
+    cross(vec_a, &cross(vec_b, vec_c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross() {
+        assert_eq!(cross(&[1, 0, 0], &[0, 1, 0]), [0, 0, 1]);
+        assert_eq!(cross(&[0, 1, 0], &[1, 0, 0]), [0, 0, -1]);
+        assert_eq!(cross(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), [-3.0, 6.0, -3.0]);
+    }
+
+    #[test]
+    fn test_perp_dot_and_perpendicular() {
+        assert_eq!(perp_dot(&[1, 0], &[0, 1]), 1);
+        assert_eq!(perp_dot(&[2, 3], &[4, 6]), 0);
+        assert_eq!(perpendicular(&[2, 1]), [-1, 2]);
+        assert_eq!(perp_dot(&[2, 1], &perpendicular(&[2, 1])), 5);
+    }
+
+    #[test]
+    fn test_triple_products() {
+        let a = [1, 2, 3];
+        let b = [4, 5, 6];
+        let c = [7, 8, 10];
+        assert_eq!(scalar_triple_product(&a, &b, &c), -3);
+        assert_eq!(scalar_triple_product(&a, &b, &c), scalar_triple_product(&b, &c, &a));
+        // a × (b × c) = b (a · c) - c (a · b)
+        let expected: [i32; 3] = core::array::from_fn(|i| b[i] * dot(&a, &c) - c[i] * dot(&a, &b));
+        assert_eq!(vector_triple_product(&a, &b, &c), expected);
+    }
+}
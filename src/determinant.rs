@@ -0,0 +1,331 @@
+use crate::{ComplexField, Elimination, RealField, Ring, VectorError, Zero};
+
+/// Matrix Determinant
+///
+/// The determinant of a square matrix. Matrices up to 4 by 4 use closed
+/// forms; larger matrices use the [`Elimination`] of the element type, which
+/// is exact fraction-free Bareiss elimination for integers and LU
+/// decomposition with partial pivoting for floats.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::determinant;
+///
+/// assert_eq!(determinant(&[[1, 2], [3, 4]]), -2);
+/// assert_eq!(determinant(&[[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]), 6.0);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to take the determinant of.
+///
+/// # Returns
+///
+/// The determinant of the matrix. The determinant of a 0 by 0 matrix is one.
+pub fn determinant<const N: usize, T: Elimination>(matrix: &[[T; N]; N]) -> T {
+    let mut scratch = *matrix;
+    determinant_in_place(scratch.as_flattened_mut(), N)
+}
+
+/// Cofactor Matrix
+///
+/// The matrix whose `(i, j)` element is the determinant of the matrix with
+/// row `i` and column `j` removed, negated when `i + j` is odd.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::cofactor;
+///
+/// assert_eq!(cofactor(&[[1, 2], [3, 4]]), [[4, -3], [-2, 1]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to take the cofactors of.
+///
+/// # Returns
+///
+/// A new matrix of cofactors.
+pub fn cofactor<const N: usize, T: Elimination>(matrix: &[[T; N]; N]) -> [[T; N]; N] {
+    let mut result = *matrix;
+    let mut scratch = *matrix;
+    cofactor_into(matrix.as_flattened(), result.as_flattened_mut(), scratch.as_flattened_mut(), N);
+    result
+}
+
+/// Adjugate Matrix
+///
+/// The transpose of the cofactor matrix, which satisfies
+/// `A adj(A) = det(A) I`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::adjugate;
+///
+/// assert_eq!(adjugate(&[[1, 2], [3, 4]]), [[4, -2], [-3, 1]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to take the adjugate of.
+///
+/// # Returns
+///
+/// A new matrix containing the adjugate.
+pub fn adjugate<const N: usize, T: Elimination>(matrix: &[[T; N]; N]) -> [[T; N]; N] {
+    crate::transpose(&cofactor(matrix))
+}
+
+/// Matrix Inverse
+///
+/// The matrix that multiplies with the input to give the identity, computed
+/// by Gauss-Jordan elimination with partial pivoting.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{inverse, VectorError};
+///
+/// assert_eq!(inverse(&[[2.0, 1.0], [1.0, 1.0]]), Ok([[1.0, -1.0], [-1.0, 2.0]]));
+/// assert_eq!(inverse(&[[1.0, 2.0], [2.0, 4.0]]), Err(VectorError::Singular));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to invert.
+///
+/// # Errors
+///
+/// Returns [`VectorError::Singular`] if a pivot is zero relative to the size
+/// of the largest element of the matrix.
+///
+/// # Returns
+///
+/// A new matrix containing the inverse.
+pub fn inverse<const N: usize, T: ComplexField>(matrix: &[[T; N]; N]) -> Result<[[T; N]; N], VectorError> {
+    let mut scratch = *matrix;
+    let mut result = crate::identity();
+    invert_in_place(scratch.as_flattened_mut(), result.as_flattened_mut(), N)?;
+    Ok(result)
+}
+
+/// The determinant of the `n` by `n` row-major matrix in `a`, which is
+/// overwritten.
+pub(crate) fn determinant_in_place<T: Elimination>(a: &mut [T], n: usize) -> T {
+    match n {
+        0 => T::one(),
+        1 => a[0],
+        2 => a[0] * a[3] - a[1] * a[2],
+        3 => {
+            a[0] * (a[4] * a[8] - a[5] * a[7]) - a[1] * (a[3] * a[8] - a[5] * a[6])
+                + a[2] * (a[3] * a[7] - a[4] * a[6])
+        }
+        4 => {
+            // Laplace expansion along the 2 by 2 minors of the first two rows.
+            let s0 = a[0] * a[5] - a[4] * a[1];
+            let s1 = a[0] * a[6] - a[4] * a[2];
+            let s2 = a[0] * a[7] - a[4] * a[3];
+            let s3 = a[1] * a[6] - a[5] * a[2];
+            let s4 = a[1] * a[7] - a[5] * a[3];
+            let s5 = a[2] * a[7] - a[6] * a[3];
+            let c5 = a[10] * a[15] - a[14] * a[11];
+            let c4 = a[9] * a[15] - a[13] * a[11];
+            let c3 = a[9] * a[14] - a[13] * a[10];
+            let c2 = a[8] * a[15] - a[12] * a[11];
+            let c1 = a[8] * a[14] - a[12] * a[10];
+            let c0 = a[8] * a[13] - a[12] * a[9];
+            s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+        }
+        _ => T::eliminate(a, n),
+    }
+}
+
+/// Fraction-free elimination: after step `k` every remaining element is a
+/// `(k + 1)` by `(k + 1)` minor, so dividing by the previous pivot is exact.
+pub(crate) fn bareiss<T: Ring + core::ops::Div<Output = T>>(a: &mut [T], n: usize) -> T {
+    let mut sign = T::one();
+    let mut previous = T::one();
+    for k in 0..n - 1 {
+        if a[k * n + k].is_zero() {
+            let Some(swap) = (k + 1..n).find(|&i| !a[i * n + k].is_zero()) else {
+                return T::zero();
+            };
+            for j in 0..n {
+                a.swap(k * n + j, swap * n + j);
+            }
+            sign = T::zero() - sign;
+        }
+        let pivot = a[k * n + k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                a[i * n + j] = (pivot * a[i * n + j] - a[i * n + k] * a[k * n + j]) / previous;
+            }
+        }
+        previous = pivot;
+    }
+    sign * a[n * n - 1]
+}
+
+/// Write the cofactors of the `n` by `n` row-major matrix `a` into `result`,
+/// using `scratch` to hold each minor.
+pub(crate) fn cofactor_into<T: Elimination>(a: &[T], result: &mut [T], scratch: &mut [T], n: usize) {
+    for i in 0..n {
+        for j in 0..n {
+            let mut index = 0;
+            for row in a.chunks_exact(n).enumerate().filter(|(r, _)| *r != i).map(|(_, row)| row) {
+                for value in row.iter().enumerate().filter(|(c, _)| *c != j).map(|(_, value)| value) {
+                    scratch[index] = *value;
+                    index += 1;
+                }
+            }
+            let minor = determinant_in_place(&mut scratch[..index], n - 1);
+            result[i * n + j] = if (i + j) % 2 == 0 { minor } else { T::zero() - minor };
+        }
+    }
+}
+
+/// Gauss-Jordan elimination of the `n` by `n` row-major matrix `a`, applying
+/// the same row operations to `result`, which must start as the identity.
+pub(crate) fn invert_in_place<T: ComplexField>(a: &mut [T], result: &mut [T], n: usize) -> Result<(), VectorError> {
+    let scale = a.iter().fold(T::Real::zero(), |max, value| max.max(value.modulus()));
+    let tolerance = scale * T::Real::epsilon() * T::Real::from_f64(n as f64);
+    for k in 0..n {
+        let pivot_row = (k..n)
+            .max_by(|&x, &y| a[x * n + k].modulus().partial_cmp(&a[y * n + k].modulus()).unwrap_or(core::cmp::Ordering::Equal))
+            .unwrap_or(k);
+        let magnitude = a[pivot_row * n + k].modulus();
+        if magnitude <= tolerance || !magnitude.is_finite() {
+            return Err(VectorError::Singular);
+        }
+        if pivot_row != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot_row * n + j);
+                result.swap(k * n + j, pivot_row * n + j);
+            }
+        }
+        let pivot = a[k * n + k].recip();
+        for j in 0..n {
+            a[k * n + j] *= pivot;
+            result[k * n + j] *= pivot;
+        }
+        for i in (0..n).filter(|&i| i != k) {
+            let factor = a[i * n + k];
+            if factor.is_zero() {
+                continue;
+            }
+            for j in 0..n {
+                let (upper, lower) = (a[k * n + j], result[k * n + j]);
+                a[i * n + j] -= factor * upper;
+                result[i * n + j] -= factor * lower;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{from_fn, identity, matrix_multiply};
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn assert_close<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) {
+        for i in 0..N {
+            for j in 0..N {
+                assert!((a[i][j] - b[i][j]).abs() < 1e-9, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinant_closed_forms() {
+        assert_eq!(determinant::<0, i32>(&[]), 1);
+        assert_eq!(determinant(&[[7]]), 7);
+        assert_eq!(determinant(&[[1, 2], [3, 4]]), -2);
+        assert_eq!(determinant(&[[6, 1, 1], [4, -2, 5], [2, 8, 7]]), -306);
+        assert_eq!(determinant(&[[1, 0, 2, -1], [3, 0, 0, 5], [2, 1, 4, -3], [1, 0, 5, 0]]), 30);
+    }
+
+    #[test]
+    fn test_determinant_elimination() {
+        // Upper triangular with a zero leading pivot after a row swap.
+        let matrix = [
+            [0, 2, 0, 0, 0],
+            [1, 0, 0, 0, 0],
+            [0, 0, 3, 0, 0],
+            [0, 0, 0, 4, 0],
+            [0, 0, 0, 0, 5],
+        ];
+        assert_eq!(determinant(&matrix), -120);
+        let vandermonde: [[i64; 6]; 6] = from_fn(|i, j| (i as i64 + 1).pow(j as u32));
+        // The product of (x_j - x_i) over i < j for x = 1..=6.
+        assert_eq!(determinant(&vandermonde), 34560);
+        let singular: [[f64; 5]; 5] = from_fn(|i, j| (i + j) as f64);
+        assert_eq!(determinant(&singular), 0.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_determinant_tiny_pivot() {
+        // Bareiss only swaps out exactly zero pivots, so a tiny leading one
+        // would swamp the rest of the matrix.
+        let matrix: [[f64; 5]; 5] = [
+            [1e-17, 1.0, 2.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0, 3.0, 0.0],
+            [2.0, 1.0, 0.0, 1.0, 4.0],
+            [0.0, 3.0, 1.0, 0.0, 2.0],
+            [1.0, 0.0, 4.0, 2.0, 1.0],
+        ];
+        let exact: [[i64; 5]; 5] = from_fn(|i, j| matrix[i][j].round() as i64);
+        let expected = determinant(&exact) as f64;
+        assert!((determinant(&matrix) - expected).abs() < 1e-9 * expected.abs());
+        assert!((crate::Lu::new(&matrix).determinant() - expected).abs() < 1e-9 * expected.abs());
+    }
+
+    #[test]
+    fn test_cofactor_and_adjugate() {
+        let matrix = [[1, 2, 3], [0, 1, 4], [5, 6, 0]];
+        assert_eq!(adjugate(&matrix), [[-24, 18, 5], [20, -15, -4], [-5, 4, 1]]);
+        let product = matrix_multiply(&matrix, &adjugate(&matrix));
+        let det = determinant(&matrix);
+        assert_eq!(product, crate::matrix_scale(&identity(), &det));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_inverse() {
+        let matrix = [[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+        let inv = inverse(&matrix).unwrap();
+        assert_close(&inv, &[[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]]);
+        let large: [[f64; 6]; 6] = from_fn(|i, j| if i == j { 10.0 } else { (i * j) as f64 * 0.1 });
+        assert_close(&matrix_multiply(&large, &inverse(&large).unwrap()), &identity());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_inverse_singular() {
+        assert_eq!(inverse(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]), Err(VectorError::Singular));
+        assert_eq!(inverse(&[[0.0_f32; 2]; 2]), Err(VectorError::Singular));
+    }
+}
@@ -0,0 +1,499 @@
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    determinant::{cofactor_into, determinant_in_place, invert_in_place},
+    try_add, try_matrix_multiply, try_matrix_vec_multiply, try_sub, ComplexField, DSymmetricEigen, Elimination, Ring,
+    ShapeError, VectorError, Zero,
+};
+
+/// Dynamically Sized Vector
+///
+/// A heap-allocated vector whose length is only known at runtime. Operations
+/// between two vectors return a [`VectorError`] when their lengths differ
+/// instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::DVector;
+///
+/// let a = DVector::from_vec(vec![1, 2, 3]);
+/// let b = DVector::from_vec(vec![3, 2, 1]);
+/// assert_eq!(a.add(&b).unwrap(), DVector::from_vec(vec![4, 4, 4]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DVector<T> {
+    data: Vec<T>,
+}
+
+impl<T> DVector<T> {
+    /// Create a vector that takes ownership of `data`.
+    pub fn from_vec(data: Vec<T>) -> Self {
+        Self { data }
+    }
+
+    /// The number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the vector has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The shape of the vector as `(rows, columns)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.data.len(), 1)
+    }
+
+    /// Borrow the elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutably borrow the elements of the vector.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Consume the vector and return its elements.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Iterate over the elements of the vector.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Copy> DVector<T> {
+    /// Create a vector by copying the elements of a slice.
+    pub fn from_slice(data: &[T]) -> Self {
+        Self { data: data.to_vec() }
+    }
+}
+
+impl<T: Zero + Copy> DVector<T> {
+    /// Create a vector of `len` zeros.
+    pub fn zeros(len: usize) -> Self {
+        Self { data: alloc::vec![T::zero(); len] }
+    }
+}
+
+impl<T: Ring> DVector<T> {
+    /// Vector Addition
+    ///
+    /// Add two vectors together. See [`try_add`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+    /// vectors are not equal.
+    pub fn add(&self, rhs: &Self) -> Result<Self, VectorError> {
+        try_add(&self.data, &rhs.data)
+    }
+
+    /// Vector Subtraction
+    ///
+    /// Subtract `rhs` from this vector. See [`try_sub`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+    /// vectors are not equal.
+    pub fn sub(&self, rhs: &Self) -> Result<Self, VectorError> {
+        try_sub(&self.data, &rhs.data)
+    }
+
+    /// Vector Scaling
+    ///
+    /// Scale the vector a specified amount.
+    pub fn scale(&self, scalar: &T) -> Self {
+        Self { data: self.data.iter().map(|a| *a * *scalar).collect() }
+    }
+}
+
+impl<T> From<Vec<T>> for DVector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for DVector<T> {
+    fn from(data: [T; N]) -> Self {
+        Self { data: data.into() }
+    }
+}
+
+impl<T> From<DVector<T>> for Vec<T> {
+    fn from(vector: DVector<T>) -> Self {
+        vector.data
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index]
+    }
+}
+
+/// Dynamically Sized Matrix
+///
+/// A heap-allocated matrix whose shape is only known at runtime, stored in
+/// row-major order. Operations between two matrices, or a matrix and a
+/// vector, return a [`VectorError`] when their shapes are incompatible
+/// instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{DMatrix, DVector};
+///
+/// let matrix = DMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+/// let vector = DVector::from_vec(vec![1, 0, -1]);
+/// assert_eq!(matrix.mul_vector(&vector).unwrap(), DVector::from_vec(vec![-2, -2]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DMatrix<T> {
+    data: Vec<T>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T> DMatrix<T> {
+    /// Create an `nrows` by `ncols` matrix that takes ownership of `data`,
+    /// given in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::DimensionMismatch`] if `data` does not hold
+    /// exactly `nrows * ncols` elements, including when that product
+    /// overflows `usize`.
+    pub fn from_vec(nrows: usize, ncols: usize, data: Vec<T>) -> Result<Self, VectorError> {
+        if nrows.checked_mul(ncols) != Some(data.len()) {
+            return Err(ShapeError { left: (nrows, ncols), right: (data.len(), 1) }.into());
+        }
+        Ok(Self { data, nrows, ncols })
+    }
+
+    /// The number of rows in the matrix.
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// The number of columns in the matrix.
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The shape of the matrix as `(rows, columns)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    /// Borrow the elements of the matrix in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutably borrow the elements of the matrix in row-major order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Consume the matrix and return its elements in row-major order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Borrow the `i`-th row of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `i` is not less than the number of rows.
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.nrows, "row index {i} out of bounds for {} rows", self.nrows);
+        &self.data[i * self.ncols..(i + 1) * self.ncols]
+    }
+}
+
+impl<T: Copy> DMatrix<T> {
+    /// Create a matrix by copying an array of rows.
+    pub fn from_rows<const M: usize, const N: usize>(rows: &[[T; N]; M]) -> Self {
+        Self { data: rows.iter().flatten().copied().collect(), nrows: M, ncols: N }
+    }
+
+    /// The `j`-th column of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `j` is not less than the number of columns.
+    pub fn column(&self, j: usize) -> DVector<T> {
+        assert!(j < self.ncols, "column index {j} out of bounds for {} columns", self.ncols);
+        DVector::from_vec(self.data.iter().skip(j).step_by(self.ncols).copied().collect())
+    }
+}
+
+impl<T: Zero + Copy> DMatrix<T> {
+    /// Create an `nrows` by `ncols` matrix of zeros.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `nrows * ncols` overflows `usize`.
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        let len = nrows.checked_mul(ncols).expect("matrix size overflows usize");
+        Self { data: alloc::vec![T::zero(); len], nrows, ncols }
+    }
+}
+
+impl<T: Ring> DMatrix<T> {
+    /// Create the `n` by `n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut result = Self::zeros(n, n);
+        for i in 0..n {
+            result[(i, i)] = T::one();
+        }
+        result
+    }
+}
+
+impl<T: Elimination> DMatrix<T> {
+    /// The determinant of the matrix. See [`determinant`](crate::determinant).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square.
+    pub fn determinant(&self) -> Result<T, VectorError> {
+        self.check_square()?;
+        Ok(determinant_in_place(&mut self.data.clone(), self.nrows))
+    }
+
+    /// The cofactor matrix. See [`cofactor`](crate::cofactor).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square.
+    pub fn cofactor(&self) -> Result<Self, VectorError> {
+        self.check_square()?;
+        let mut result = self.clone();
+        cofactor_into(&self.data, &mut result.data, &mut self.data.clone(), self.nrows);
+        Ok(result)
+    }
+
+    /// The transpose of the cofactor matrix. See
+    /// [`adjugate`](crate::adjugate).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square.
+    pub fn adjugate(&self) -> Result<Self, VectorError> {
+        Ok(self.cofactor()?.transpose())
+    }
+}
+
+impl<T: ComplexField> DMatrix<T> {
+    /// The conjugate transpose. See
+    /// [`conjugate_transpose`](crate::conjugate_transpose).
+    pub fn conjugate_transpose(&self) -> Self {
+        let mut result = self.transpose();
+        for value in &mut result.data {
+            *value = value.conj();
+        }
+        result
+    }
+
+    /// The inverse of the matrix. See [`inverse`](crate::inverse).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square, or
+    /// [`VectorError::Singular`] if it is singular.
+    pub fn inverse(&self) -> Result<Self, VectorError> {
+        self.check_square()?;
+        let mut result = Self::identity(self.nrows);
+        invert_in_place(&mut self.data.clone(), &mut result.data, self.nrows)?;
+        Ok(result)
+    }
+
+    /// The eigendecomposition of the Hermitian matrix. See
+    /// [`DSymmetricEigen`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square, or
+    /// [`VectorError::NoConvergence`] if the decomposition does not converge.
+    pub fn symmetric_eigen(&self) -> Result<DSymmetricEigen<T>, VectorError> {
+        DSymmetricEigen::new(self)
+    }
+}
+
+impl<T> DMatrix<T> {
+    fn check_square(&self) -> Result<(), VectorError> {
+        if self.nrows == self.ncols {
+            Ok(())
+        } else {
+            Err(VectorError::NotSquare { shape: self.shape() })
+        }
+    }
+}
+
+impl<T: Copy> DMatrix<T> {
+    /// Swap the rows and columns of the matrix.
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.ncols {
+            data.extend(self.data.iter().skip(j).step_by(self.ncols));
+        }
+        Self { data, nrows: self.ncols, ncols: self.nrows }
+    }
+}
+
+impl<T: Ring> DMatrix<T> {
+    /// Matrix Vector Multiplication
+    ///
+    /// Multiply the vector by the matrix. See [`try_matrix_vec_multiply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::DimensionMismatch`] if the length of the vector
+    /// is not equal to the number of columns in the matrix.
+    pub fn mul_vector(&self, vector: &DVector<T>) -> Result<DVector<T>, VectorError> {
+        try_matrix_vec_multiply(self, &vector.data)
+    }
+
+    /// Matrix Multiplication
+    ///
+    /// Multiply this matrix by `rhs`. See [`try_matrix_multiply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::DimensionMismatch`] if the number of columns in
+    /// this matrix is not equal to the number of rows in `rhs`.
+    pub fn mul_matrix(&self, rhs: &Self) -> Result<Self, VectorError> {
+        try_matrix_multiply(self, rhs)
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> From<[[T; N]; M]> for DMatrix<T> {
+    fn from(rows: [[T; N]; M]) -> Self {
+        Self::from_rows(&rows)
+    }
+}
+
+/// Index by `(row, column)`.
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.nrows && j < self.ncols, "index ({i}, {j}) out of bounds for {}x{} matrix", self.nrows, self.ncols);
+        &self.data[i * self.ncols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.nrows && j < self.ncols, "index ({i}, {j}) out of bounds for {}x{} matrix", self.nrows, self.ncols);
+        &mut self.data[i * self.ncols + j]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_dvector_add_sub() {
+        let a = DVector::from_vec(vec![1, 2, 3]);
+        let b = DVector::from_vec(vec![3, 2, 1]);
+        assert_eq!(a.add(&b), Ok(DVector::from_vec(vec![4, 4, 4])));
+        assert_eq!(a.sub(&b), Ok(DVector::from_vec(vec![-2, 0, 2])));
+        assert_eq!(a.scale(&2), DVector::from_vec(vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn test_dvector_shape_mismatch() {
+        let a = DVector::from_vec(vec![1, 2, 3]);
+        let b = DVector::from_vec(vec![1, 2]);
+        assert_eq!(a.add(&b), Err(VectorError::DimensionMismatch(ShapeError { left: (3, 1), right: (2, 1) })));
+        assert_eq!(a.sub(&b), Err(VectorError::DimensionMismatch(ShapeError { left: (3, 1), right: (2, 1) })));
+    }
+
+    #[test]
+    fn test_dmatrix_construction() {
+        let matrix = DMatrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(matrix, DMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(matrix.row(1), &[4, 5, 6]);
+        assert_eq!(matrix.column(2), DVector::from_vec(vec![3, 6]));
+        assert_eq!(matrix.transpose(), DMatrix::from_rows(&[[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(DMatrix::identity(2), DMatrix::from_rows(&[[1, 0], [0, 1]]));
+        assert_eq!(
+            DMatrix::from_vec(2, 2, vec![1, 2, 3]),
+            Err(VectorError::DimensionMismatch(ShapeError { left: (2, 2), right: (3, 1) }))
+        );
+        assert_eq!(
+            DMatrix::<i32>::from_vec(usize::MAX, 2, vec![]),
+            Err(VectorError::DimensionMismatch(ShapeError { left: (usize::MAX, 2), right: (0, 1) }))
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_dmatrix_determinant_and_inverse() {
+        let a = DMatrix::from_rows(&[[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+        assert_eq!(a.determinant(), Ok(1));
+        assert_eq!(a.adjugate(), Ok(DMatrix::from_rows(&[[-24, 18, 5], [20, -15, -4], [-5, 4, 1]])));
+        let b = DMatrix::from_rows(&[[2.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(b.inverse(), Ok(DMatrix::from_rows(&[[1.0, -1.0], [-1.0, 2.0]])));
+        assert_eq!(DMatrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]]).inverse(), Err(VectorError::Singular));
+        let c = DMatrix::from_rows(&[[1, 2, 3]]);
+        assert_eq!(c.determinant(), Err(VectorError::NotSquare { shape: (1, 3) }));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_dmatrix_symmetric_eigen() {
+        let matrix = DMatrix::from_rows(&[[2.0_f64, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        let eigen = matrix.symmetric_eigen().unwrap();
+        let root = 2.0_f64.sqrt();
+        for (value, expected) in eigen.eigenvalues().iter().zip([2.0 - root, 2.0, 2.0 + root]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let v = eigen.eigenvectors();
+        let product = v.transpose().mul_matrix(v).unwrap();
+        for (value, expected) in product.as_slice().iter().zip(DMatrix::identity(3).as_slice()) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let rectangular = DMatrix::from_rows(&[[1.0_f64, 2.0]]);
+        assert_eq!(rectangular.symmetric_eigen(), Err(VectorError::NotSquare { shape: (1, 2) }));
+    }
+
+    #[test]
+    fn test_dmatrix_multiply() {
+        let a = DMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+        let b = DMatrix::from_rows(&[[1, 0], [0, 1], [1, -1]]);
+        assert_eq!(a.mul_matrix(&b), Ok(DMatrix::from_rows(&[[4, -1], [10, -1]])));
+        assert_eq!(a.mul_vector(&DVector::from_vec(vec![1, 0, -1])), Ok(DVector::from_vec(vec![-2, -2])));
+        assert_eq!(a.mul_matrix(&a), Err(VectorError::DimensionMismatch(ShapeError { left: (2, 3), right: (2, 3) })));
+        assert_eq!(a.mul_vector(&DVector::from_vec(vec![1, 2])), Err(VectorError::DimensionMismatch(ShapeError { left: (2, 3), right: (2, 1) })));
+    }
+}
@@ -0,0 +1,890 @@
+#[cfg(feature = "alloc")]
+use crate::{DMatrix, DVector};
+use crate::{hessenberg::hessenberg_in_place, Abs, Complex, ComplexField, Field, One, RealField, VectorError, Zero};
+
+/// The number of sweeps [`SymmetricEigen::new`] performs before giving up.
+const DEFAULT_MAX_SWEEPS: usize = 64;
+
+/// The number of QR steps [`Eigen`] spends on each eigenvalue before giving
+/// up.
+const MAX_QR_ITERATIONS: usize = 60;
+
+/// Symmetric Eigendecomposition
+///
+/// The factorization `A = V Λ Vᴴ` of a real symmetric or complex Hermitian
+/// matrix, where `Λ` holds the real eigenvalues in ascending order and the
+/// columns of `V` are the matching orthonormal eigenvectors. Computed with
+/// cyclic Jacobi rotations, which are slower than tridiagonal QR but find
+/// small eigenvalues to high relative accuracy. Only the lower triangle of
+/// the input is read, and the imaginary parts of its diagonal are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::SymmetricEigen;
+///
+/// let eigen = SymmetricEigen::new(&[[2.0_f64, 1.0], [1.0, 2.0]]).unwrap();
+/// let [small, large] = eigen.eigenvalues();
+/// assert!((small - 1.0).abs() < 1e-12 && (large - 3.0).abs() < 1e-12);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymmetricEigen<T: ComplexField, const N: usize> {
+    eigenvalues: [T::Real; N],
+    eigenvectors: [[T; N]; N],
+}
+
+impl<T: ComplexField, const N: usize> SymmetricEigen<T, N> {
+    /// Decompose `matrix` to machine precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if the off-diagonal elements
+    /// have not vanished after 64 sweeps, which only happens for matrices
+    /// with non-finite elements.
+    pub fn new(matrix: &[[T; N]; N]) -> Result<Self, VectorError> {
+        Self::with_settings(matrix, T::Real::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Decompose `matrix`, stopping once the norm of the off-diagonal
+    /// elements is at most `tolerance` times the norm of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if that has not happened after
+    /// `max_sweeps` sweeps over every off-diagonal element.
+    pub fn with_settings(matrix: &[[T; N]; N], tolerance: T::Real, max_sweeps: usize) -> Result<Self, VectorError> {
+        let mut a = *matrix;
+        let mut eigenvectors = crate::identity();
+        let mut eigenvalues = [T::Real::zero(); N];
+        jacobi_eigen_in_place(
+            a.as_flattened_mut(),
+            eigenvectors.as_flattened_mut(),
+            &mut eigenvalues,
+            N,
+            tolerance,
+            max_sweeps,
+        )?;
+        Ok(Self { eigenvalues, eigenvectors })
+    }
+
+    /// The eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> [T::Real; N] {
+        self.eigenvalues
+    }
+
+    /// The eigenvectors as the columns of a unitary matrix, in the same
+    /// order as the eigenvalues.
+    pub fn eigenvectors(&self) -> [[T; N]; N] {
+        self.eigenvectors
+    }
+}
+
+/// Dynamically Sized Symmetric Eigendecomposition
+///
+/// The same decomposition as [`SymmetricEigen`] for a [`DMatrix`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::DMatrix;
+///
+/// let matrix = DMatrix::from_rows(&[[2.0, 0.0], [0.0, 1.0]]);
+/// let eigen = matrix.symmetric_eigen().unwrap();
+/// assert_eq!(eigen.eigenvalues().as_slice(), &[1.0, 2.0]);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DSymmetricEigen<T: ComplexField> {
+    eigenvalues: DVector<T::Real>,
+    eigenvectors: DMatrix<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ComplexField> DSymmetricEigen<T> {
+    /// Decompose `matrix` to machine precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square, or
+    /// [`VectorError::NoConvergence`] if it has not converged after 64
+    /// sweeps.
+    pub fn new(matrix: &DMatrix<T>) -> Result<Self, VectorError> {
+        Self::with_settings(matrix, T::Real::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Decompose `matrix` with the given tolerance and sweep limit. See
+    /// [`SymmetricEigen::with_settings`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotSquare`] if the matrix is not square, or
+    /// [`VectorError::NoConvergence`] if it has not converged after
+    /// `max_sweeps` sweeps.
+    pub fn with_settings(matrix: &DMatrix<T>, tolerance: T::Real, max_sweeps: usize) -> Result<Self, VectorError> {
+        let (nrows, ncols) = matrix.shape();
+        if nrows != ncols {
+            return Err(VectorError::NotSquare { shape: (nrows, ncols) });
+        }
+        let mut a = matrix.as_slice().to_vec();
+        let mut eigenvectors = DMatrix::identity(nrows);
+        let mut eigenvalues = DVector::zeros(nrows);
+        jacobi_eigen_in_place(
+            &mut a,
+            eigenvectors.as_mut_slice(),
+            eigenvalues.as_mut_slice(),
+            nrows,
+            tolerance,
+            max_sweeps,
+        )?;
+        Ok(Self { eigenvalues, eigenvectors })
+    }
+
+    /// The eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> &DVector<T::Real> {
+        &self.eigenvalues
+    }
+
+    /// The eigenvectors as the columns of a unitary matrix, in the same
+    /// order as the eigenvalues.
+    pub fn eigenvectors(&self) -> &DMatrix<T> {
+        &self.eigenvectors
+    }
+}
+
+/// General Eigendecomposition
+///
+/// The eigenvalues of a real square matrix, which may be complex, and
+/// optionally the matching eigenvectors. The matrix is reduced to
+/// [`Hessenberg`](crate::Hessenberg) form and then to quasi-triangular form
+/// with the Francis double-shift QR algorithm, which keeps the arithmetic
+/// real. Eigenvectors are found afterwards by inverse iteration.
+///
+/// Eigenvalues are sorted by real part and then by imaginary part, so
+/// complex conjugate pairs are adjacent.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{Complex, Eigen};
+///
+/// // A quarter turn has no real eigenvalues.
+/// let eigen = Eigen::new(&[[0.0, -1.0], [1.0, 0.0]]).unwrap();
+/// assert_eq!(eigen.eigenvalues(), [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)]);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Eigen<T, const N: usize> {
+    eigenvalues: [Complex<T>; N],
+    eigenvectors: Option<[[Complex<T>; N]; N]>,
+}
+
+impl<T: RealField, const N: usize> Eigen<T, N> {
+    /// Find the eigenvalues of `matrix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if an eigenvalue is not
+    /// isolated after 60 QR steps.
+    pub fn new(matrix: &[[T; N]; N]) -> Result<Self, VectorError> {
+        let (mut h, scale) = normalized(matrix);
+        let mut scratch = [T::zero(); N];
+        hessenberg_in_place(h.as_flattened_mut(), None, &mut scratch, N);
+        let mut re = [T::zero(); N];
+        let mut im = [T::zero(); N];
+        hqr_in_place(h.as_flattened_mut(), &mut re, &mut im, N)?;
+        let mut eigenvalues: [Complex<T>; N] = core::array::from_fn(|i| Complex::new(re[i] * scale, im[i] * scale));
+        sort_eigenvalues(&mut eigenvalues);
+        Ok(Self { eigenvalues, eigenvectors: None })
+    }
+
+    /// Find the eigenvalues of `matrix` and a unit eigenvector for each.
+    /// When inverse iteration returns a vector parallel to one already found,
+    /// the eigenvalue is repeated and it is found again orthogonal to the
+    /// vectors of nearby eigenvalues, so a diagonalizable matrix gets
+    /// independent eigenvectors. A defective matrix has fewer independent
+    /// eigenvectors than eigenvalues, and the extra vectors for its repeated
+    /// eigenvalues are not eigenvectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if an eigenvalue is not
+    /// isolated after 60 QR steps.
+    pub fn with_eigenvectors(matrix: &[[T; N]; N]) -> Result<Self, VectorError> {
+        let mut eigen = Self::new(matrix)?;
+        let mut columns = [[Complex::from_real(T::zero()); N]; N];
+        let mut scratch = [[Complex::from_real(T::zero()); N]; N];
+        let mut group = [[Complex::from_real(T::zero()); N]; N];
+        let mut pivots = [0; N];
+        // Eigenvectors do not change with the scale of the matrix.
+        let (matrix, scale) = normalized(matrix);
+        let a = matrix.as_flattened();
+        let eigenvalues = eigen.eigenvalues.map(|value| Complex::new(value.re / scale, value.im / scale));
+        let parallel = T::epsilon().sqrt();
+        for j in 0..N {
+            let eigenvalue = eigenvalues[j];
+            let (done, rest) = columns.split_at_mut(j);
+            let x = &mut rest[0];
+            inverse_iteration(a, eigenvalue, &[], scratch.as_flattened_mut(), &mut pivots, x, N);
+            let repeated = done.iter().any(|column| {
+                let mut remainder = *x;
+                orthogonalize(&mut remainder, column);
+                length(&remainder) <= parallel
+            });
+            if !repeated {
+                continue;
+            }
+            // Find the vector again orthogonal to an orthonormal basis for
+            // the ones already found for nearby eigenvalues.
+            let tolerance = (T::one() + eigenvalue.modulus()) * T::epsilon().sqrt();
+            let mut count = 0;
+            for (i, column) in done.iter().enumerate() {
+                if (eigenvalues[i] - eigenvalue).modulus() > tolerance {
+                    continue;
+                }
+                let (basis, rest) = group.split_at_mut(count);
+                let vector = &mut rest[0];
+                *vector = *column;
+                orthogonalize(vector, basis.as_flattened());
+                let remaining = length(vector);
+                if remaining > parallel {
+                    for value in vector.iter_mut() {
+                        *value = Complex::new(value.re / remaining, value.im / remaining);
+                    }
+                    count += 1;
+                }
+            }
+            inverse_iteration(a, eigenvalue, group[..count].as_flattened(), scratch.as_flattened_mut(), &mut pivots, x, N);
+        }
+        eigen.eigenvectors = Some(crate::transpose(&columns));
+        Ok(eigen)
+    }
+
+    /// The eigenvalues, sorted by real part and then by imaginary part.
+    pub fn eigenvalues(&self) -> [Complex<T>; N] {
+        self.eigenvalues
+    }
+
+    /// The eigenvectors as the columns of a matrix, in the same order as the
+    /// eigenvalues, if they were requested.
+    pub fn eigenvectors(&self) -> Option<[[Complex<T>; N]; N]> {
+        self.eigenvectors
+    }
+}
+
+/// `matrix` divided by its largest element, along with that element, so that
+/// the QR iterations and inverse iteration neither overflow nor underflow.
+fn normalized<T: RealField, const N: usize>(matrix: &[[T; N]; N]) -> ([[T; N]; N], T) {
+    let largest = matrix.as_flattened().iter().fold(T::zero(), |max, value| max.max(value.abs()));
+    let scale = if largest.is_zero() || !largest.is_finite() { T::one() } else { largest };
+    (matrix.map(|row| row.map(|value| value / scale)), scale)
+}
+
+fn sort_eigenvalues<T: RealField>(eigenvalues: &mut [Complex<T>]) {
+    let before = |a: &Complex<T>, b: &Complex<T>| a.re < b.re || (a.re == b.re && a.im < b.im);
+    for i in 0..eigenvalues.len() {
+        let first = (i..eigenvalues.len()).fold(i, |best, j| if before(&eigenvalues[j], &eigenvalues[best]) { j } else { best });
+        eigenvalues.swap(i, first);
+    }
+}
+
+/// Find the eigenvalues of the `n` by `n` row-major upper Hessenberg matrix
+/// in `a`, which is overwritten, with the Francis double-shift QR algorithm.
+/// This follows the classic EISPACK `hqr` routine, indexing from one.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn hqr_in_place<T: RealField>(a: &mut [T], re: &mut [T], im: &mut [T], n: usize) -> Result<(), VectorError> {
+    macro_rules! at {
+        ($i:expr, $j:expr) => {
+            a[($i - 1) * n + ($j - 1)]
+        };
+    }
+    let zero = T::zero();
+    let norm = (0..n).fold(zero, |sum, i| (i.saturating_sub(1)..n).fold(sum, |sum, j| sum + a[i * n + j].abs()));
+    let (mut p, mut q, mut r, mut s);
+    let (mut w, mut x, mut y, mut z);
+    // The accumulated exceptional shifts.
+    let mut t = zero;
+    let mut nn = n;
+    while nn >= 1 {
+        let mut iterations = 0;
+        loop {
+            // Look for a negligible subdiagonal element to split the matrix.
+            let mut l = nn;
+            while l >= 2 {
+                s = at!(l - 1, l - 1).abs() + at!(l, l).abs();
+                if s.is_zero() {
+                    s = norm;
+                }
+                if at!(l, l - 1).abs() + s == s {
+                    at!(l, l - 1) = zero;
+                    break;
+                }
+                l -= 1;
+            }
+            x = at!(nn, nn);
+            if l == nn {
+                // One root found.
+                re[nn - 1] = x + t;
+                im[nn - 1] = zero;
+                nn -= 1;
+            } else {
+                y = at!(nn - 1, nn - 1);
+                w = at!(nn, nn - 1) * at!(nn - 1, nn);
+                if l == nn - 1 {
+                    // Two roots found, from the trailing 2 by 2 block.
+                    p = T::from_f64(0.5) * (y - x);
+                    q = p * p + w;
+                    z = q.abs().sqrt();
+                    x += t;
+                    if q >= zero {
+                        z = if p < zero { p - z } else { p + z };
+                        re[nn - 2] = x + z;
+                        re[nn - 1] = if z.is_zero() { x + z } else { x - w / z };
+                        im[nn - 2] = zero;
+                        im[nn - 1] = zero;
+                    } else {
+                        re[nn - 2] = x + p;
+                        re[nn - 1] = x + p;
+                        im[nn - 2] = -z;
+                        im[nn - 1] = z;
+                    }
+                    nn -= 2;
+                } else {
+                    if iterations == MAX_QR_ITERATIONS || !x.is_finite() {
+                        return Err(VectorError::NoConvergence { iterations });
+                    }
+                    if iterations > 0 && iterations % 10 == 0 {
+                        // An exceptional shift breaks cycles.
+                        t += x;
+                        for i in 1..=nn {
+                            at!(i, i) -= x;
+                        }
+                        s = at!(nn, nn - 1).abs() + at!(nn - 1, nn - 2).abs();
+                        x = T::from_f64(0.75) * s;
+                        y = x;
+                        w = T::from_f64(-0.4375) * s * s;
+                    }
+                    iterations += 1;
+                    // Look for two consecutive small subdiagonal elements.
+                    let mut m = nn - 2;
+                    loop {
+                        z = at!(m, m);
+                        r = x - z;
+                        s = y - z;
+                        p = (r * s - w) / at!(m + 1, m) + at!(m, m + 1);
+                        q = at!(m + 1, m + 1) - z - r - s;
+                        r = at!(m + 2, m + 1);
+                        s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = at!(m, m - 1).abs() * (q.abs() + r.abs());
+                        let v = p.abs() * (at!(m - 1, m - 1).abs() + z.abs() + at!(m + 1, m + 1).abs());
+                        if u + v == v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                    for i in m + 2..=nn {
+                        at!(i, i - 2) = zero;
+                        if i != m + 2 {
+                            at!(i, i - 3) = zero;
+                        }
+                    }
+                    // The double QR step on rows l to nn and columns m to nn.
+                    for k in m..nn {
+                        if k != m {
+                            p = at!(k, k - 1);
+                            q = at!(k + 1, k - 1);
+                            r = if k != nn - 1 { at!(k + 2, k - 1) } else { zero };
+                            x = p.abs() + q.abs() + r.abs();
+                            if !x.is_zero() {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+                        let root = (p * p + q * q + r * r).sqrt();
+                        s = if p < zero { -root } else { root };
+                        if s.is_zero() {
+                            continue;
+                        }
+                        if k == m {
+                            if l != m {
+                                at!(k, k - 1) = -at!(k, k - 1);
+                            }
+                        } else {
+                            at!(k, k - 1) = -s * x;
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        z = r / s;
+                        q /= p;
+                        r /= p;
+                        for j in k..=nn {
+                            p = at!(k, j) + q * at!(k + 1, j);
+                            if k != nn - 1 {
+                                p += r * at!(k + 2, j);
+                                at!(k + 2, j) -= p * z;
+                            }
+                            at!(k + 1, j) -= p * y;
+                            at!(k, j) -= p * x;
+                        }
+                        for i in l..=nn.min(k + 3) {
+                            p = x * at!(i, k) + y * at!(i, k + 1);
+                            if k != nn - 1 {
+                                p += z * at!(i, k + 2);
+                                at!(i, k + 2) -= p * r;
+                            }
+                            at!(i, k + 1) -= p * q;
+                            at!(i, k) -= p;
+                        }
+                    }
+                }
+            }
+            if l + 1 >= nn {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write a unit eigenvector of the `n` by `n` row-major matrix `a` for
+/// `eigenvalue` to `x` by inverse iteration: solving `(A - λ I) y = x` twice,
+/// which amplifies the components of `x` along the eigenvector. `x` is kept
+/// orthogonal to the unit vectors stored back to back in `deflate`, which are
+/// eigenvectors already found for the same eigenvalue. `scratch` holds the
+/// factored shifted matrix and `pivots` its row swaps.
+fn inverse_iteration<T: RealField>(
+    a: &[T],
+    eigenvalue: Complex<T>,
+    deflate: &[Complex<T>],
+    scratch: &mut [Complex<T>],
+    pivots: &mut [usize],
+    x: &mut [Complex<T>],
+    n: usize,
+) {
+    let zero = Complex::from_real(T::zero());
+    let scale = a.iter().fold(T::zero(), |max, value| max.max(value.abs()));
+    // λ is an eigenvalue to working precision, so the shifted matrix is
+    // singular and pivots this small are replaced.
+    let tiny = (scale + eigenvalue.modulus()).max(T::one()) * T::epsilon();
+    for (i, row) in scratch.chunks_exact_mut(n).enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = Complex::from_real(a[i * n + j]);
+        }
+        row[i] -= eigenvalue;
+    }
+    // Gaussian elimination with partial pivoting, keeping the multipliers
+    // below the diagonal.
+    for k in 0..n {
+        let pivot_row = (k..n).fold(k, |best, i| {
+            if scratch[i * n + k].modulus() > scratch[best * n + k].modulus() {
+                i
+            } else {
+                best
+            }
+        });
+        pivots[k] = pivot_row;
+        if pivot_row != k {
+            for j in 0..n {
+                scratch.swap(k * n + j, pivot_row * n + j);
+            }
+        }
+        if scratch[k * n + k].modulus() <= tiny {
+            scratch[k * n + k] = Complex::from_real(tiny);
+        }
+        let pivot = scratch[k * n + k];
+        for i in k + 1..n {
+            let factor = scratch[i * n + k] / pivot;
+            scratch[i * n + k] = factor;
+            for j in k + 1..n {
+                let upper = scratch[k * n + j];
+                scratch[i * n + j] -= factor * upper;
+            }
+        }
+    }
+    let lu = &*scratch;
+    let solve = |x: &mut [Complex<T>]| {
+        for k in 0..n {
+            x.swap(k, pivots[k]);
+            for i in k + 1..n {
+                let upper = x[k];
+                x[i] -= lu[i * n + k] * upper;
+            }
+        }
+        for i in (0..n).rev() {
+            let sum = (i + 1..n).fold(x[i], |sum, j| sum - lu[i * n + j] * x[j]);
+            x[i] = sum / lu[i * n + i];
+        }
+        // Solving amplifies every vector for this eigenvalue alike, including
+        // the ones already found.
+        orthogonalize(x, deflate);
+    };
+    // A start vector with no component along the eigenvector is not
+    // amplified at all, so try the vector of ones and then each coordinate
+    // axis, keeping the one that grows the most.
+    let start = |x: &mut [Complex<T>], candidate: usize| {
+        for (i, value) in x.iter_mut().enumerate() {
+            *value = Complex::from_real(if candidate == n || i == candidate { T::one() } else { T::zero() });
+        }
+        orthogonalize(x, deflate);
+    };
+    let enough = T::epsilon().sqrt().recip();
+    let mut best = (n, T::zero());
+    for candidate in core::iter::once(n).chain(0..n) {
+        start(x, candidate);
+        let before = length(x);
+        if before <= T::epsilon().sqrt() {
+            continue;
+        }
+        solve(x);
+        let growth = length(x) / before;
+        if growth > best.1 {
+            best = (candidate, growth);
+        }
+        if growth >= enough {
+            break;
+        }
+    }
+    start(x, best.0);
+    for _ in 0..2 {
+        solve(x);
+        // Scale the largest component to one, which also fixes the phase.
+        let largest = x.iter().fold(zero, |best, value| if value.modulus() > best.modulus() { *value } else { best });
+        if largest == zero {
+            return;
+        }
+        for value in x.iter_mut() {
+            *value /= largest;
+        }
+    }
+    let length = Complex::from_real(length(x));
+    for value in x.iter_mut() {
+        *value /= length;
+    }
+}
+
+/// Remove from `x` its components along the orthonormal vectors stored back
+/// to back in `basis`, twice so that rounding does not leave any behind.
+fn orthogonalize<T: RealField>(x: &mut [Complex<T>], basis: &[Complex<T>]) {
+    for _ in 0..2 {
+        for q in basis.chunks_exact(x.len()) {
+            let along = q.iter().zip(x.iter()).fold(Complex::from_real(T::zero()), |sum, (q, x)| sum + q.conj() * *x);
+            for (value, q) in x.iter_mut().zip(q) {
+                *value -= along * *q;
+            }
+        }
+    }
+}
+
+fn length<T: RealField>(x: &[Complex<T>]) -> T {
+    x.iter().fold(T::zero(), |sum, value| sum + value.norm_sqr()).sqrt()
+}
+
+/// Diagonalize the Hermitian `n` by `n` row-major matrix in `a`, given by
+/// its lower triangle, with Jacobi rotations, accumulating them into `v`,
+/// which must start as the identity. The sorted eigenvalues are written to
+/// `eigenvalues` and the columns of `v` are sorted to match. The matrix is
+/// divided by its largest element first, so the sums of squares behind the
+/// stopping test neither overflow nor underflow.
+pub(crate) fn jacobi_eigen_in_place<T: ComplexField>(
+    a: &mut [T],
+    v: &mut [T],
+    eigenvalues: &mut [T::Real],
+    n: usize,
+    tolerance: T::Real,
+    max_sweeps: usize,
+) -> Result<(), VectorError> {
+    for i in 0..n {
+        a[i * n + i] = T::from_real(a[i * n + i].real());
+        for j in i + 1..n {
+            a[i * n + j] = a[j * n + i].conj();
+        }
+    }
+    let largest = a.iter().fold(T::Real::zero(), |max, value| max.max(value.modulus()));
+    let scale = if largest.is_zero() || !largest.is_finite() { T::Real::one() } else { largest };
+    for value in a.iter_mut() {
+        *value /= T::from_real(scale);
+    }
+    let total = a.iter().fold(T::Real::zero(), |sum, value| sum + value.norm_sqr());
+    let threshold = tolerance * tolerance * total;
+    let mut sweeps = 0;
+    loop {
+        let off_diagonal = (0..n).fold(T::Real::zero(), |sum, i| {
+            (0..n).filter(|&j| j != i).fold(sum, |sum, j| sum + a[i * n + j].norm_sqr())
+        });
+        if off_diagonal <= threshold {
+            break;
+        }
+        if sweeps == max_sweeps || !off_diagonal.is_finite() {
+            return Err(VectorError::NoConvergence { iterations: sweeps });
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                rotate(a, v, n, p, q);
+            }
+        }
+        sweeps += 1;
+    }
+    for (i, eigenvalue) in eigenvalues.iter_mut().enumerate() {
+        *eigenvalue = a[i * n + i].real() * scale;
+    }
+    // Selection sort keeps this allocation free; n is small in practice.
+    for i in 0..n {
+        let smallest = (i..n).fold(i, |best, j| if eigenvalues[j] < eigenvalues[best] { j } else { best });
+        if smallest != i {
+            eigenvalues.swap(i, smallest);
+            for row in v.chunks_exact_mut(n) {
+                row.swap(i, smallest);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Apply the rotation in the `(p, q)` plane that zeroes `a[p][q]`. The phase
+/// of `a[p][q]` goes into the rotation, and is a sign for real matrices.
+fn rotate<T: ComplexField>(a: &mut [T], v: &mut [T], n: usize, p: usize, q: usize) {
+    let apq = a[p * n + q];
+    if apq.is_zero() {
+        return;
+    }
+    let two = T::Real::one() + T::Real::one();
+    let magnitude = apq.modulus();
+    let theta = (a[q * n + q].real() - a[p * n + p].real()) / (two * magnitude);
+    let t = (theta.abs() + (theta * theta + T::Real::one()).sqrt()).recip();
+    let t = if theta < T::Real::zero() { -t } else { t };
+    let c = (t * t + T::Real::one()).sqrt().recip();
+    let phase = apq / T::from_real(magnitude);
+    let (c, s) = (T::from_real(c), T::from_real(t * c));
+    for k in 0..n {
+        let (akp, akq) = (a[k * n + p], a[k * n + q]);
+        a[k * n + p] = c * akp - s * phase.conj() * akq;
+        a[k * n + q] = s * phase * akp + c * akq;
+    }
+    for k in 0..n {
+        let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+        a[p * n + k] = c * apk - s * phase * aqk;
+        a[q * n + k] = s * phase.conj() * apk + c * aqk;
+    }
+    a[p * n + q] = T::zero();
+    a[q * n + p] = T::zero();
+    for k in 0..n {
+        let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+        v[k * n + p] = c * vkp - s * phase.conj() * vkq;
+        v[k * n + q] = s * phase * vkp + c * vkq;
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{determinant, diagonal, identity, matrix_multiply, trace, transpose};
+
+    fn assert_close<const M: usize, const N: usize>(a: &[[f64; N]; M], b: &[[f64; N]; M]) {
+        for (x, y) in a.as_flattened().iter().zip(b.as_flattened()) {
+            assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+
+    fn assert_decomposes<const N: usize>(matrix: &[[f64; N]; N]) -> SymmetricEigen<f64, N> {
+        let eigen = SymmetricEigen::new(matrix).unwrap();
+        let v = eigen.eigenvectors();
+        assert_close(&matrix_multiply(&transpose(&v), &v), &identity());
+        let reconstructed = matrix_multiply(&matrix_multiply(&v, &diagonal(&eigen.eigenvalues())), &transpose(&v));
+        assert_close(&reconstructed, matrix);
+        assert!(eigen.eigenvalues().windows(2).all(|pair| pair[0] <= pair[1]));
+        eigen
+    }
+
+    #[test]
+    fn test_known_eigenvalues() {
+        let eigen = assert_decomposes(&[[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        let root = 2.0_f64.sqrt();
+        assert_close(&[eigen.eigenvalues()], &[[2.0 - root, 2.0, 2.0 + root]]);
+        let eigen = assert_decomposes(&[[4.0, 0.0], [0.0, -3.0]]);
+        assert_eq!(eigen.eigenvalues(), [-3.0, 4.0]);
+        assert_eq!(eigen.eigenvectors(), [[0.0, 1.0], [1.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_inertia_tensor() {
+        // Repeated eigenvalues still give an orthonormal basis.
+        let eigen = assert_decomposes(&[[2.0, 0.0, 0.0], [0.0, 3.0, 1.0], [0.0, 1.0, 3.0]]);
+        assert_close(&[eigen.eigenvalues()], &[[2.0, 2.0, 4.0]]);
+        let hilbert: [[f64; 6]; 6] = crate::from_fn(|i, j| 1.0 / (i + j + 1) as f64);
+        let eigen = assert_decomposes(&hilbert);
+        // The smallest eigenvalue of the 6 by 6 Hilbert matrix.
+        assert!((eigen.eigenvalues()[0] / 1.082_799_484_565_5e-7 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_settings() {
+        let matrix = [[1.0, 2.0], [2.0, 1.0]];
+        assert_eq!(SymmetricEigen::with_settings(&matrix, 1e-12, 0), Err(VectorError::NoConvergence { iterations: 0 }));
+        let eigen = SymmetricEigen::with_settings(&matrix, 1e-3, 10).unwrap();
+        assert_close(&[eigen.eigenvalues()], &[[-1.0, 3.0]]);
+        let nan = [[f64::NAN, 1.0], [1.0, 0.0]];
+        assert!(matches!(SymmetricEigen::new(&nan), Err(VectorError::NoConvergence { .. })));
+    }
+
+    #[test]
+    fn test_symmetric_extreme_magnitudes() {
+        let root = 5.0_f64.sqrt();
+        for scale in [1e160, 1e-170] {
+            let matrix = [[2.0 * scale, scale], [scale, 3.0 * scale]];
+            let eigen = SymmetricEigen::new(&matrix).unwrap();
+            let expected = [(5.0 - root) / 2.0 * scale, (5.0 + root) / 2.0 * scale];
+            for (value, expected) in eigen.eigenvalues().iter().zip(expected) {
+                assert!((value / expected - 1.0).abs() < 1e-12, "{value} != {expected}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermitian() {
+        use crate::conjugate_transpose;
+        let c = Complex::new;
+        let eigen = SymmetricEigen::new(&[[c(2.0, 0.0), c(1.0, -1.0)], [c(1.0, 1.0), c(3.0, 0.0)]]).unwrap();
+        let [small, large] = eigen.eigenvalues();
+        assert!((small - 1.0).abs() < 1e-12 && (large - 4.0).abs() < 1e-12);
+        let matrix = [[c(4.0, 0.0), c(1.0, -2.0), c(0.0, 0.0)], [c(1.0, 2.0), c(3.0, 0.0), c(0.0, -1.0)], [c(0.0, 0.0), c(0.0, 1.0), c(1.0, 0.0)]];
+        // Only the real parts of the diagonal and the lower triangle are read.
+        let mut lower = matrix;
+        lower[0][0].im = 5.0;
+        lower[0][1] = c(7.0, 7.0);
+        let eigen = SymmetricEigen::new(&lower).unwrap();
+        let v = eigen.eigenvectors();
+        for (x, y) in matrix_multiply(&conjugate_transpose(&v), &v).as_flattened().iter().zip(identity::<3, Complex<f64>>().as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        let lambda = diagonal(&eigen.eigenvalues().map(Complex::from_real));
+        let reconstructed = matrix_multiply(&matrix_multiply(&v, &lambda), &conjugate_transpose(&v));
+        for (x, y) in reconstructed.as_flattened().iter().zip(matrix.as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        assert!((eigen.eigenvalues().iter().sum::<f64>() - 8.0).abs() < 1e-12);
+        #[cfg(feature = "alloc")]
+        assert_eq!(DMatrix::from_rows(&lower).symmetric_eigen().unwrap().eigenvalues().as_slice(), &eigen.eigenvalues());
+    }
+
+    fn assert_complex_close(a: Complex<f64>, b: Complex<f64>) {
+        assert!((a - b).modulus() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    fn assert_eigenvectors<const N: usize>(matrix: &[[f64; N]; N]) -> Eigen<f64, N> {
+        let eigen = Eigen::with_eigenvectors(matrix).unwrap();
+        let vectors = eigen.eigenvectors().unwrap();
+        for (k, eigenvalue) in eigen.eigenvalues().iter().enumerate() {
+            for (i, row) in matrix.iter().enumerate() {
+                let product = row.iter().enumerate().fold(Complex::from_real(0.0), |sum, (j, value)| sum + Complex::from_real(*value) * vectors[j][k]);
+                assert_complex_close(product, *eigenvalue * vectors[i][k]);
+            }
+        }
+        eigen
+    }
+
+    #[test]
+    fn test_general_eigenvalues() {
+        let eigen = assert_eigenvectors(&[[1.0, 2.0, 0.0], [-2.0, 1.0, 0.0], [0.0, 0.0, 3.0]]);
+        let expected = [Complex::new(1.0, -2.0), Complex::new(1.0, 2.0), Complex::new(3.0, 0.0)];
+        for (value, expected) in eigen.eigenvalues().iter().zip(expected) {
+            assert_complex_close(*value, expected);
+        }
+        let eigen = assert_eigenvectors(&[[2.0, 1.0, 5.0], [0.0, -1.0, 4.0], [0.0, 0.0, 7.0]]);
+        assert_eq!(eigen.eigenvalues().map(|value| value.re), [-1.0, 2.0, 7.0]);
+        assert_eq!(Eigen::new(&[[0.0; 3]; 3]).unwrap().eigenvalues(), [Complex::from_real(0.0); 3]);
+    }
+
+    #[test]
+    fn test_repeated_eigenvalues() {
+        let diagonal = assert_eigenvectors(&[[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+        let vectors = diagonal.eigenvectors().unwrap();
+        assert!(vectors[2][0].modulus() < 1e-12 && vectors[2][1].modulus() < 1e-12);
+        // Similar to diag(2, 2, 3), so two independent vectors share λ = 2.
+        let matrix = [[2.0, 0.0, 0.0], [1.0, 1.0, 1.0], [2.0, -2.0, 4.0]];
+        let eigen = assert_eigenvectors(&matrix);
+        assert_complex_close(eigen.eigenvalues()[0], Complex::from_real(2.0));
+        assert_complex_close(eigen.eigenvalues()[1], Complex::from_real(2.0));
+        for eigen in [diagonal, eigen] {
+            let vectors = eigen.eigenvectors().unwrap();
+            assert!(crate::Lu::new(&vectors).determinant().modulus() > 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_close_distinct_eigenvalues() {
+        // A non-normal matrix whose eigenvectors (1, 0) and about (1, 1e-6)
+        // are nearly parallel but still independent.
+        let matrix = [[1.0, 1e-3], [0.0, 1.0 + 1e-9]];
+        let eigen = Eigen::with_eigenvectors(&matrix).unwrap();
+        let vectors = eigen.eigenvectors().unwrap();
+        for (k, eigenvalue) in eigen.eigenvalues().iter().enumerate() {
+            let residual = matrix.iter().enumerate().fold(0.0_f64, |max, (i, row)| {
+                let product = row.iter().enumerate().fold(Complex::from_real(0.0), |sum, (j, value)| sum + Complex::from_real(*value) * vectors[j][k]);
+                max.max((product - *eigenvalue * vectors[i][k]).modulus())
+            });
+            assert!(residual < 1e-12, "residual {residual} for {eigenvalue:?}");
+        }
+    }
+
+    #[test]
+    fn test_general_extreme_magnitudes() {
+        let root = 5.0_f64.sqrt();
+        for scale in [1e160, 1e-170] {
+            let matrix = [[2.0 * scale, scale], [scale, 3.0 * scale]];
+            let eigen = Eigen::with_eigenvectors(&matrix).unwrap();
+            let expected = [(5.0 - root) / 2.0 * scale, (5.0 + root) / 2.0 * scale];
+            for (value, expected) in eigen.eigenvalues().iter().zip(expected) {
+                assert!((value.re / expected - 1.0).abs() < 1e-12 && value.im == 0.0, "{value:?} != {expected}");
+            }
+            let vectors = eigen.eigenvectors().unwrap();
+            assert!((vectors[0][0].re * vectors[0][1].re + vectors[1][0].re * vectors[1][1].re).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_general_eigenvalue_invariants() {
+        // The companion matrix of x⁵ - 1, whose eigenvalues are the fifth roots of unity.
+        let mut companion = [[0.0; 5]; 5];
+        for i in 1..5 {
+            companion[i][i - 1] = 1.0;
+        }
+        companion[0][4] = 1.0;
+        let eigen = assert_eigenvectors(&companion);
+        for value in eigen.eigenvalues() {
+            assert!((value.modulus() - 1.0).abs() < 1e-12);
+        }
+        let matrix: [[f64; 6]; 6] = crate::from_fn(|i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0);
+        let eigenvalues = assert_eigenvectors(&matrix).eigenvalues();
+        let sum = eigenvalues.iter().fold(Complex::from_real(0.0), |sum, value| sum + *value);
+        let product = eigenvalues.iter().fold(Complex::from_real(1.0), |product, value| product * *value);
+        assert_complex_close(sum, Complex::from_real(trace(&matrix)));
+        assert!((product - Complex::from_real(determinant(&matrix))).modulus() < 1e-6);
+        assert!(Eigen::new(&matrix).unwrap().eigenvectors().is_none());
+    }
+}
@@ -0,0 +1,115 @@
+use core::fmt;
+
+/// Shape Mismatch
+///
+/// Describes the operands of a dynamically sized operation whose shapes are
+/// incompatible, carried by [`VectorError::DimensionMismatch`]. Shapes are
+/// given as `(rows, columns)`, with vectors reported as a single column.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{ShapeError, VectorError};
+///
+/// let error = ShapeError { left: (2, 3), right: (2, 1) };
+/// assert_eq!(error.to_string(), "incompatible shapes 2x3 and 2x1");
+/// assert_eq!(VectorError::from(error), VectorError::DimensionMismatch(error));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShapeError {
+    /// The shape of the left operand.
+    pub left: (usize, usize),
+    /// The shape of the right operand.
+    pub right: (usize, usize),
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "incompatible shapes {}x{} and {}x{}",
+            self.left.0, self.left.1, self.right.0, self.right.1
+        )
+    }
+}
+
+impl core::error::Error for ShapeError {}
+
+/// Vector Operation Error
+///
+/// The error returned by the fallible `try_*` operations, the dynamically
+/// sized types and the matrix decompositions.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{project_onto, VectorError};
+///
+/// let result = project_onto(&[1.0, 2.0], &[0.0, 0.0]);
+/// assert_eq!(result, Err(VectorError::ZeroVector));
+/// assert_eq!(VectorError::ZeroVector.to_string(), "vector has zero length");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VectorError {
+    /// The shapes of the operands are incompatible.
+    DimensionMismatch(ShapeError),
+    /// The matrix is singular, so it has no inverse.
+    Singular,
+    /// An iterative algorithm did not converge within its iteration limit.
+    NoConvergence {
+        /// The number of iterations that were performed.
+        iterations: usize,
+    },
+    /// An integer operation overflowed.
+    Overflow,
+    /// The vector has zero length, so it has no direction.
+    ZeroVector,
+    /// The operation needs a square matrix.
+    NotSquare {
+        /// The shape of the matrix as `(rows, columns)`.
+        shape: (usize, usize),
+    },
+    /// The matrix is not positive-definite, so it has no Cholesky factor.
+    NotPositiveDefinite,
+    /// A vector lies in the span of the vectors before it, so it adds no new
+    /// direction to the basis.
+    LinearlyDependent {
+        /// The position of the vector in the input.
+        index: usize,
+    },
+}
+
+impl From<ShapeError> for VectorError {
+    fn from(error: ShapeError) -> Self {
+        VectorError::DimensionMismatch(error)
+    }
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::DimensionMismatch(error) => write!(f, "dimension mismatch: {error}"),
+            VectorError::Singular => f.write_str("matrix is singular"),
+            VectorError::NoConvergence { iterations } => {
+                write!(f, "did not converge after {iterations} iterations")
+            }
+            VectorError::Overflow => f.write_str("arithmetic overflow"),
+            VectorError::ZeroVector => f.write_str("vector has zero length"),
+            VectorError::NotSquare { shape } => write!(f, "{}x{} matrix is not square", shape.0, shape.1),
+            VectorError::NotPositiveDefinite => f.write_str("matrix is not positive-definite"),
+            VectorError::LinearlyDependent { index } => {
+                write!(f, "vector {index} is linearly dependent on the ones before it")
+            }
+        }
+    }
+}
+
+impl core::error::Error for VectorError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            VectorError::DimensionMismatch(error) => Some(error),
+            _ => None,
+        }
+    }
+}
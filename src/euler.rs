@@ -0,0 +1,309 @@
+use crate::{identity, matrix_multiply, RealField};
+
+/// Euler Angle Order
+///
+/// The sequence of axes that three Euler angles rotate about. The six
+/// Tait–Bryan orders use all three axes, as in yaw, pitch and roll, and the
+/// six proper Euler orders repeat the first axis.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::EulerOrder;
+///
+/// assert_eq!(EulerOrder::Zyx.axes(), [2, 1, 0]);
+/// assert!(EulerOrder::Zxz.is_proper());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// Rotate about `x`, then `y`, then `z`.
+    Xyz,
+    /// Rotate about `x`, then `z`, then `y`.
+    Xzy,
+    /// Rotate about `y`, then `x`, then `z`.
+    Yxz,
+    /// Rotate about `y`, then `z`, then `x`.
+    Yzx,
+    /// Rotate about `z`, then `x`, then `y`.
+    Zxy,
+    /// Rotate about `z`, then `y`, then `x`.
+    Zyx,
+    /// Rotate about `x`, then `y`, then `x` again.
+    Xyx,
+    /// Rotate about `x`, then `z`, then `x` again.
+    Xzx,
+    /// Rotate about `y`, then `x`, then `y` again.
+    Yxy,
+    /// Rotate about `y`, then `z`, then `y` again.
+    Yzy,
+    /// Rotate about `z`, then `x`, then `z` again.
+    Zxz,
+    /// Rotate about `z`, then `y`, then `z` again.
+    Zyz,
+}
+
+impl EulerOrder {
+    /// Every order, Tait–Bryan first.
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::Xyz,
+        EulerOrder::Xzy,
+        EulerOrder::Yxz,
+        EulerOrder::Yzx,
+        EulerOrder::Zxy,
+        EulerOrder::Zyx,
+        EulerOrder::Xyx,
+        EulerOrder::Xzx,
+        EulerOrder::Yxy,
+        EulerOrder::Yzy,
+        EulerOrder::Zxz,
+        EulerOrder::Zyz,
+    ];
+
+    /// The indices of the three axes, with `x`, `y` and `z` as 0, 1 and 2.
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::Xyz => [0, 1, 2],
+            EulerOrder::Xzy => [0, 2, 1],
+            EulerOrder::Yxz => [1, 0, 2],
+            EulerOrder::Yzx => [1, 2, 0],
+            EulerOrder::Zxy => [2, 0, 1],
+            EulerOrder::Zyx => [2, 1, 0],
+            EulerOrder::Xyx => [0, 1, 0],
+            EulerOrder::Xzx => [0, 2, 0],
+            EulerOrder::Yxy => [1, 0, 1],
+            EulerOrder::Yzy => [1, 2, 1],
+            EulerOrder::Zxz => [2, 0, 2],
+            EulerOrder::Zyz => [2, 1, 2],
+        }
+    }
+
+    /// Whether the first and last axes are the same.
+    pub const fn is_proper(self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+
+    /// The order with the axes reversed. Proper orders are their own
+    /// reverse.
+    pub const fn reverse(self) -> Self {
+        match self {
+            EulerOrder::Xyz => EulerOrder::Zyx,
+            EulerOrder::Xzy => EulerOrder::Yzx,
+            EulerOrder::Yxz => EulerOrder::Zxy,
+            EulerOrder::Yzx => EulerOrder::Xzy,
+            EulerOrder::Zxy => EulerOrder::Yxz,
+            EulerOrder::Zyx => EulerOrder::Xyz,
+            proper => proper,
+        }
+    }
+}
+
+/// Euler Angle Frame
+///
+/// Whether each rotation of an Euler angle sequence is about the axes of the
+/// already rotated body or about the fixed world axes. Intrinsic `Zyx` with
+/// angles `[a, b, c]` is the same rotation as extrinsic `Xyz` with angles
+/// `[c, b, a]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerFrame {
+    /// Rotate about the axes of the body, which move with each rotation.
+    Intrinsic,
+    /// Rotate about the fixed axes of the world.
+    Extrinsic,
+}
+
+/// Euler Angles to Rotation Matrix
+///
+/// The rotation matrix of three Euler angles applied in `order`. Matrices
+/// act on column vectors, so intrinsic `Zyx` with angles `[yaw, pitch,
+/// roll]` is `Rz(yaw) Ry(pitch) Rx(roll)`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{euler_to_rotation_matrix, matrix_vec_multiply, EulerFrame, EulerOrder};
+///
+/// let yaw = core::f64::consts::FRAC_PI_2;
+/// let matrix = euler_to_rotation_matrix(&[yaw, 0.0, 0.0], EulerOrder::Zyx, EulerFrame::Intrinsic);
+/// let heading = matrix_vec_multiply(&matrix, &[1.0, 0.0, 0.0]);
+/// assert!(heading[0].abs() < 1e-15 && (heading[1] - 1.0).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `angles`: The three angles in radians, in the order they are applied.
+/// - `order`: The axes to rotate about.
+/// - `frame`: Whether the axes move with the body or stay fixed.
+///
+/// # Returns
+///
+/// A new 3×3 rotation matrix.
+pub fn euler_to_rotation_matrix<T: RealField>(angles: &[T; 3], order: EulerOrder, frame: EulerFrame) -> [[T; 3]; 3] {
+    let (angles, order) = intrinsic(angles, order, frame);
+    order
+        .axes()
+        .iter()
+        .zip(angles)
+        .fold(identity(), |product, (axis, angle)| matrix_multiply(&product, &axis_rotation(*axis, angle)))
+}
+
+/// Rotation Matrix to Euler Angles
+///
+/// The Euler angles of a rotation matrix in `order`. The middle angle is in
+/// `[-π/2, π/2]` for Tait–Bryan orders and `[0, π]` for proper Euler orders,
+/// and the others are in `(-π, π]`.
+///
+/// At gimbal lock, where the middle angle makes the first and last axes
+/// line up, only their sum or difference is determined. One angle is then
+/// reported as zero and the other carries the whole rotation: the last
+/// angle (`angles[2]`) for [`EulerFrame::Intrinsic`], and the first angle
+/// (`angles[0]`) for [`EulerFrame::Extrinsic`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{euler_to_rotation_matrix, rotation_matrix_to_euler, EulerFrame, EulerOrder};
+///
+/// let angles = [0.1_f64, -0.2, 0.3];
+/// let matrix = euler_to_rotation_matrix(&angles, EulerOrder::Xyz, EulerFrame::Extrinsic);
+/// let recovered = rotation_matrix_to_euler(&matrix, EulerOrder::Xyz, EulerFrame::Extrinsic);
+/// for (a, b) in angles.iter().zip(recovered) {
+///     assert!((a - b).abs() < 1e-12);
+/// }
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The rotation matrix, which must be orthogonal with a
+///   determinant of one.
+/// - `order`: The axes to rotate about.
+/// - `frame`: Whether the axes move with the body or stay fixed.
+///
+/// # Returns
+///
+/// The three angles in radians, in the order they are applied.
+pub fn rotation_matrix_to_euler<T: RealField>(matrix: &[[T; 3]; 3], order: EulerOrder, frame: EulerFrame) -> [T; 3] {
+    let order = match frame {
+        EulerFrame::Intrinsic => order,
+        EulerFrame::Extrinsic => order.reverse(),
+    };
+    let [i, j, _] = order.axes();
+    // The remaining axis, and the sign that makes (i, j, k) right-handed.
+    let k = 3 - i - j;
+    let sign = if (j + 3 - i) % 3 == 1 { T::one() } else { -T::one() };
+    let r = matrix;
+    let zero = T::zero();
+    let tolerance = T::epsilon().sqrt();
+    let (first, middle, last) = if order.is_proper() {
+        let sine = (r[i][j] * r[i][j] + r[i][k] * r[i][k]).sqrt();
+        let middle = sine.atan2(r[i][i]);
+        if sine > tolerance {
+            (r[j][i].atan2(-sign * r[k][i]), middle, r[i][j].atan2(sign * r[i][k]))
+        } else {
+            ((sign * r[k][j]).atan2(r[j][j]), middle, zero)
+        }
+    } else {
+        let cosine = (r[i][i] * r[i][i] + r[i][j] * r[i][j]).sqrt();
+        let middle = (sign * r[i][k]).atan2(cosine);
+        if cosine > tolerance {
+            ((-sign * r[j][k]).atan2(r[k][k]), middle, (-sign * r[i][j]).atan2(r[i][i]))
+        } else {
+            ((sign * r[k][j]).atan2(r[j][j]), middle, zero)
+        }
+    };
+    match frame {
+        EulerFrame::Intrinsic => [first, middle, last],
+        EulerFrame::Extrinsic => [last, middle, first],
+    }
+}
+
+/// The intrinsic angles and order equivalent to `angles` in `order` and
+/// `frame`.
+pub(crate) fn intrinsic<T: Copy>(angles: &[T; 3], order: EulerOrder, frame: EulerFrame) -> ([T; 3], EulerOrder) {
+    match frame {
+        EulerFrame::Intrinsic => (*angles, order),
+        EulerFrame::Extrinsic => ([angles[2], angles[1], angles[0]], order.reverse()),
+    }
+}
+
+/// The rotation by `angle` about coordinate axis `axis`.
+fn axis_rotation<T: RealField>(axis: usize, angle: T) -> [[T; 3]; 3] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+    let mut result = identity();
+    result[j][j] = cos;
+    result[k][k] = cos;
+    result[k][j] = sin;
+    result[j][k] = -sin;
+    result
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-9, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_known_matrices() {
+        // Yaw, pitch and roll: Rz(yaw) Ry(pitch) Rx(roll).
+        let (yaw, pitch, roll) = (0.3_f64, -0.4_f64, 1.1_f64);
+        let expected = [
+            [yaw.cos() * pitch.cos(), yaw.cos() * pitch.sin() * roll.sin() - yaw.sin() * roll.cos(), yaw.cos() * pitch.sin() * roll.cos() + yaw.sin() * roll.sin()],
+            [yaw.sin() * pitch.cos(), yaw.sin() * pitch.sin() * roll.sin() + yaw.cos() * roll.cos(), yaw.sin() * pitch.sin() * roll.cos() - yaw.cos() * roll.sin()],
+            [-pitch.sin(), pitch.cos() * roll.sin(), pitch.cos() * roll.cos()],
+        ];
+        let intrinsic = euler_to_rotation_matrix(&[yaw, pitch, roll], EulerOrder::Zyx, EulerFrame::Intrinsic);
+        let extrinsic = euler_to_rotation_matrix(&[roll, pitch, yaw], EulerOrder::Xyz, EulerFrame::Extrinsic);
+        assert_close(intrinsic.as_flattened(), expected.as_flattened());
+        assert_close(extrinsic.as_flattened(), expected.as_flattened());
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let frames = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+        for order in EulerOrder::ALL {
+            for frame in frames {
+                let middles: &[f64] = if order.is_proper() { &[0.4, 1.5, 2.9] } else { &[-1.2, 0.0, 0.7] };
+                for &middle in middles {
+                    let angles = [0.5, middle, -2.0];
+                    let matrix = euler_to_rotation_matrix(&angles, order, frame);
+                    let recovered = rotation_matrix_to_euler(&matrix, order, frame);
+                    assert_close(&recovered, &angles);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        let frames = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+        for order in EulerOrder::ALL {
+            for frame in frames {
+                let middles: &[f64] = if order.is_proper() { &[0.0, PI] } else { &[FRAC_PI_2, -FRAC_PI_2] };
+                for &middle in middles {
+                    let matrix = euler_to_rotation_matrix(&[0.5, middle, -0.25], order, frame);
+                    let recovered = rotation_matrix_to_euler(&matrix, order, frame);
+                    assert!(recovered.iter().all(|angle| angle.is_finite()));
+                    let zeroed = match frame {
+                        EulerFrame::Intrinsic => recovered[2],
+                        EulerFrame::Extrinsic => recovered[0],
+                    };
+                    assert_eq!(zeroed, 0.0);
+                    // The angles differ, but the rotation is the same.
+                    let rebuilt = euler_to_rotation_matrix(&recovered, order, frame);
+                    assert_close(rebuilt.as_flattened(), matrix.as_flattened());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::Ring;
+#[cfg(feature = "alloc")]
+use crate::{DMatrix, DVector, Integer, ShapeError, VectorError};
+
+/// Fallible Vector Subtraction
+///
+/// Subtract two vectors whose lengths are only known at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_sub, DVector};
+///
+/// let a = [1, 2];
+/// let b = [5, 4];
+/// assert_eq!(try_sub(&a, &b), Ok(DVector::from_vec(vec![-4, -2])));
+/// assert!(try_sub(&a, &[1]).is_err());
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+/// vectors are not equal.
+///
+/// # Returns
+///
+/// A new vector containing the difference of the two input vectors.
+#[cfg(feature = "alloc")]
+pub fn try_sub<T: Ring>(vec_a: &[T], vec_b: &[T]) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(vec_a.len());
+    zip_into(vec_a, vec_b, result.as_mut_slice(), |a, b| Some(sub_element(a, b)))?;
+    Ok(result)
+}
+
+/// Fallible Vector Addition
+///
+/// Add two vectors whose lengths are only known at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_add, DVector};
+///
+/// let a = [1, 2, 3];
+/// let b = [3, 2, 1];
+/// assert_eq!(try_add(&a, &b), Ok(DVector::from_vec(vec![4, 4, 4])));
+/// assert!(try_add(&a, &[1]).is_err());
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+/// vectors are not equal.
+///
+/// # Returns
+///
+/// A new vector containing the sum of the two input vectors.
+#[cfg(feature = "alloc")]
+pub fn try_add<T: Ring>(vec_a: &[T], vec_b: &[T]) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(vec_a.len());
+    zip_into(vec_a, vec_b, result.as_mut_slice(), |a, b| Some(add_element(a, b)))?;
+    Ok(result)
+}
+
+/// Fallible Matrix Vector Multiplication
+///
+/// Multiply a vector by a matrix whose shapes are only known at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_matrix_vec_multiply, DMatrix, DVector};
+///
+/// let matrix = DMatrix::from_rows(&[[1, -3], [2, 4]]);
+/// assert_eq!(try_matrix_vec_multiply(&matrix, &[5, 7]), Ok(DVector::from_vec(vec![-16, 38])));
+/// assert!(try_matrix_vec_multiply(&matrix, &[5]).is_err());
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the length of the vector is
+/// not equal to the number of columns in the matrix.
+///
+/// # Returns
+///
+/// A new vector containing the result of multiplying the matrix and vector.
+#[cfg(feature = "alloc")]
+pub fn try_matrix_vec_multiply<T: Ring>(
+    matrix: &DMatrix<T>,
+    vector: &[T],
+) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(matrix.nrows());
+    matrix_vec_multiply_into(matrix.as_slice(), matrix.shape(), vector, result.as_mut_slice(), |sum, a, b| {
+        Some(multiply_add(sum, a, b))
+    })?;
+    Ok(result)
+}
+
+/// Fallible Matrix Multiplication
+///
+/// Multiply two matrices whose shapes are only known at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_matrix_multiply, DMatrix};
+///
+/// let a = DMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+/// let b = DMatrix::from_rows(&[[1, 0], [0, 1], [1, -1]]);
+/// assert_eq!(try_matrix_multiply(&a, &b), Ok(DMatrix::from_rows(&[[4, -1], [10, -1]])));
+/// assert!(try_matrix_multiply(&a, &a).is_err());
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix_a`: The left matrix.
+/// - `matrix_b`: The right matrix.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the number of columns in
+/// the first matrix is not equal to the number of rows in the second.
+///
+/// # Returns
+///
+/// A new matrix containing the product of the two input matrices.
+#[cfg(feature = "alloc")]
+pub fn try_matrix_multiply<T: Ring>(
+    matrix_a: &DMatrix<T>,
+    matrix_b: &DMatrix<T>,
+) -> Result<DMatrix<T>, VectorError> {
+    if matrix_a.ncols() != matrix_b.nrows() {
+        return Err(ShapeError { left: matrix_a.shape(), right: matrix_b.shape() }.into());
+    }
+    let mut result = DMatrix::zeros(matrix_a.nrows(), matrix_b.ncols());
+    for i in 0..matrix_a.nrows() {
+        for k in 0..matrix_a.ncols() {
+            let a = matrix_a[(i, k)];
+            for j in 0..matrix_b.ncols() {
+                result[(i, j)] += a * matrix_b[(k, j)];
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Fallible Checked Vector Addition
+///
+/// Add two integer vectors whose lengths are only known at runtime,
+/// reporting overflow as an error.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_checked_add, DVector, VectorError};
+///
+/// assert_eq!(try_checked_add(&[1u8, 2], &[3, 4]), Ok(DVector::from_vec(vec![4, 6])));
+/// assert_eq!(try_checked_add(&[250u8, 1], &[10, 1]), Err(VectorError::Overflow));
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+/// vectors are not equal, or [`VectorError::Overflow`] if any element
+/// overflows.
+///
+/// # Returns
+///
+/// A new vector containing the sum of the two input vectors.
+#[cfg(feature = "alloc")]
+pub fn try_checked_add<T: Integer>(vec_a: &[T], vec_b: &[T]) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(vec_a.len());
+    zip_into(vec_a, vec_b, result.as_mut_slice(), T::checked_add)?;
+    Ok(result)
+}
+
+/// Fallible Checked Vector Subtraction
+///
+/// Subtract two integer vectors whose lengths are only known at runtime,
+/// reporting overflow as an error.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_checked_sub, DVector, VectorError};
+///
+/// assert_eq!(try_checked_sub(&[5u8, 4], &[1, 2]), Ok(DVector::from_vec(vec![4, 2])));
+/// assert_eq!(try_checked_sub(&[1u8, 2], &[3, 4]), Err(VectorError::Overflow));
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the lengths of the two
+/// vectors are not equal, or [`VectorError::Overflow`] if any element
+/// overflows.
+///
+/// # Returns
+///
+/// A new vector containing the difference of the two input vectors.
+#[cfg(feature = "alloc")]
+pub fn try_checked_sub<T: Integer>(vec_a: &[T], vec_b: &[T]) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(vec_a.len());
+    zip_into(vec_a, vec_b, result.as_mut_slice(), T::checked_sub)?;
+    Ok(result)
+}
+
+/// Fallible Checked Matrix Vector Multiplication
+///
+/// Multiply an integer vector by a matrix whose shapes are only known at
+/// runtime, reporting overflow as an error.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{try_checked_matrix_vec_multiply, DMatrix, DVector, VectorError};
+///
+/// let matrix = DMatrix::from_rows(&[[100u8, 100], [1, 2]]);
+/// assert_eq!(try_checked_matrix_vec_multiply(&matrix, &[1, 1]), Ok(DVector::from_vec(vec![200, 3])));
+/// assert_eq!(try_checked_matrix_vec_multiply(&matrix, &[2, 1]), Err(VectorError::Overflow));
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the length of the vector is
+/// not equal to the number of columns in the matrix, or
+/// [`VectorError::Overflow`] if any product or partial sum overflows.
+///
+/// # Returns
+///
+/// A new vector containing the result of multiplying the matrix and vector.
+#[cfg(feature = "alloc")]
+pub fn try_checked_matrix_vec_multiply<T: Integer>(
+    matrix: &DMatrix<T>,
+    vector: &[T],
+) -> Result<DVector<T>, VectorError> {
+    let mut result = DVector::zeros(matrix.nrows());
+    matrix_vec_multiply_into(matrix.as_slice(), matrix.shape(), vector, result.as_mut_slice(), |sum, a, b| {
+        sum.checked_add(a.checked_mul(b)?)
+    })?;
+    Ok(result)
+}
+
+/// One element of [`add`](crate::add) and [`try_add`].
+pub(crate) fn add_element<T: Ring>(a: T, b: T) -> T {
+    a + b
+}
+
+/// One element of [`sub`](crate::sub) and [`try_sub`].
+pub(crate) fn sub_element<T: Ring>(a: T, b: T) -> T {
+    a - b
+}
+
+/// One step of the dot products in
+/// [`matrix_vec_multiply`](crate::matrix_vec_multiply) and
+/// [`try_matrix_vec_multiply`].
+pub(crate) fn multiply_add<T: Ring>(sum: T, a: T, b: T) -> T {
+    sum + a * b
+}
+
+/// Combine `vec_a` and `vec_b` element by element into `result`, which must
+/// be as long as `vec_a`, reporting mismatched lengths. `op` returns `None`
+/// to report overflow.
+#[cfg(feature = "alloc")]
+fn zip_into<T: Copy>(
+    vec_a: &[T],
+    vec_b: &[T],
+    result: &mut [T],
+    op: impl Fn(T, T) -> Option<T>,
+) -> Result<(), VectorError> {
+    if vec_a.len() != vec_b.len() {
+        return Err(ShapeError { left: (vec_a.len(), 1), right: (vec_b.len(), 1) }.into());
+    }
+    for (r, (a, b)) in result.iter_mut().zip(vec_a.iter().zip(vec_b)) {
+        *r = op(*a, *b).ok_or(VectorError::Overflow)?;
+    }
+    Ok(())
+}
+
+/// Multiply the row-major `matrix` of the given `(rows, columns)` shape by
+/// `vector` into `result`, which must have one element per row.
+/// `multiply_add` adds the product of its last two arguments to the first
+/// and returns `None` to report overflow.
+#[cfg(feature = "alloc")]
+fn matrix_vec_multiply_into<T: Ring>(
+    matrix: &[T],
+    shape: (usize, usize),
+    vector: &[T],
+    result: &mut [T],
+    multiply_add: impl Fn(T, T, T) -> Option<T>,
+) -> Result<(), VectorError> {
+    let (rows, columns) = shape;
+    if columns != vector.len() {
+        return Err(ShapeError { left: shape, right: (vector.len(), 1) }.into());
+    }
+    for (i, r) in result.iter_mut().enumerate().take(rows) {
+        let mut sum = T::zero();
+        for (a, b) in matrix[i * columns..(i + 1) * columns].iter().zip(vector) {
+            sum = multiply_add(sum, *a, *b).ok_or(VectorError::Overflow)?;
+        }
+        *r = sum;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn test_try_add_sub() {
+        let a = [1, 2, 3];
+        let b = [3, 2, 1];
+        assert_eq!(try_add(&a, &b), Ok(DVector::from_vec(vec![4, 4, 4])));
+        assert_eq!(try_sub(&a, &b), Ok(DVector::from_vec(vec![-2, 0, 2])));
+        let expected = VectorError::DimensionMismatch(ShapeError { left: (3, 1), right: (2, 1) });
+        assert_eq!(try_add(&a, &[1, 2]), Err(expected));
+        assert_eq!(try_sub(&a, &[1, 2]), Err(expected));
+    }
+
+    #[test]
+    fn test_try_matrix_multiply() {
+        let a = DMatrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+        let b = DMatrix::from_rows(&[[1, 0], [0, 1], [1, -1]]);
+        assert_eq!(try_matrix_multiply(&a, &b), Ok(DMatrix::from_rows(&[[4, -1], [10, -1]])));
+        assert_eq!(
+            try_matrix_multiply(&a, &a),
+            Err(VectorError::DimensionMismatch(ShapeError { left: (2, 3), right: (2, 3) }))
+        );
+        assert_eq!(try_matrix_vec_multiply(&a, &[1, 0, -1]), Ok(DVector::from_vec(vec![-2, -2])));
+        assert_eq!(
+            try_matrix_vec_multiply(&a, &[1, 2]),
+            Err(VectorError::DimensionMismatch(ShapeError { left: (2, 3), right: (2, 1) }))
+        );
+    }
+
+    #[test]
+    fn test_try_checked() {
+        assert_eq!(try_checked_add(&[1i8, -2], &[3, 4]), Ok(DVector::from_vec(vec![4, 2])));
+        assert_eq!(try_checked_add(&[127i8, 0], &[1, 0]), Err(VectorError::Overflow));
+        assert_eq!(try_checked_sub(&[-128i8], &[1]), Err(VectorError::Overflow));
+        let expected = VectorError::DimensionMismatch(ShapeError { left: (2, 1), right: (1, 1) });
+        assert_eq!(try_checked_add(&[1u8, 2], &[1]), Err(expected));
+        assert_eq!(try_checked_sub(&[1u8, 2], &[1]), Err(expected));
+        let matrix = DMatrix::from_rows(&[[100i8, 27], [-100, 1]]);
+        assert_eq!(try_checked_matrix_vec_multiply(&matrix, &[1, 1]), Ok(DVector::from_vec(vec![127, -99])));
+        assert_eq!(try_checked_matrix_vec_multiply(&matrix, &[1, 2]), Err(VectorError::Overflow));
+        assert_eq!(try_checked_matrix_vec_multiply(&matrix, &[2, 0]), Err(VectorError::Overflow));
+        assert_eq!(
+            try_checked_matrix_vec_multiply(&matrix, &[1]),
+            Err(VectorError::DimensionMismatch(ShapeError { left: (2, 2), right: (1, 1) }))
+        );
+    }
+
+    #[test]
+    fn test_vector_error_display() {
+        let error = VectorError::from(ShapeError { left: (2, 3), right: (2, 1) });
+        assert_eq!(error.to_string(), "dimension mismatch: incompatible shapes 2x3 and 2x1");
+        assert_eq!(VectorError::NoConvergence { iterations: 10 }.to_string(), "did not converge after 10 iterations");
+    }
+}
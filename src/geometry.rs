@@ -0,0 +1,328 @@
+use crate::{dot, norm_l2, perp_dot, scale, sub, Field, RealField, Ring, VectorError};
+
+/// Vector Projection
+///
+/// The component of `vec` along `onto`: the multiple of `onto` closest to
+/// `vec`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{project_onto, VectorError};
+///
+/// assert_eq!(project_onto(&[2.0, 3.0], &[4.0, 0.0]), Ok([2.0, 0.0]));
+/// assert_eq!(project_onto(&[2.0, 3.0], &[0.0, 0.0]), Err(VectorError::ZeroVector));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to project.
+/// - `onto`: The vector to project onto, which does not need to have unit
+///   length.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if `onto` is the zero vector.
+///
+/// # Returns
+///
+/// A new vector parallel to `onto`.
+pub fn project_onto<const F: usize, T: Field>(vec: &[T; F], onto: &[T; F]) -> Result<[T; F], VectorError> {
+    let length_squared = dot(onto, onto);
+    if length_squared.is_zero() {
+        return Err(VectorError::ZeroVector);
+    }
+    Ok(scale(onto, &(dot(vec, onto) / length_squared)))
+}
+
+/// Vector Rejection
+///
+/// The component of `vec` perpendicular to `from`, which is what remains
+/// after subtracting the projection onto it.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::reject_from;
+///
+/// assert_eq!(reject_from(&[2.0, 3.0], &[4.0, 0.0]), Ok([0.0, 3.0]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to reject.
+/// - `from`: The vector to remove the component along.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if `from` is the zero vector.
+///
+/// # Returns
+///
+/// A new vector perpendicular to `from`.
+pub fn reject_from<const F: usize, T: Field>(vec: &[T; F], from: &[T; F]) -> Result<[T; F], VectorError> {
+    Ok(sub(vec, &project_onto(vec, from)?))
+}
+
+/// Vector Reflection
+///
+/// Mirror `vec` across the plane with unit normal `normal`, as a ray
+/// bounces off a surface: `v - 2 (v · n) n`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::reflect;
+///
+/// assert_eq!(reflect(&[1, -1], &[0, 1]), [1, 1]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to reflect.
+/// - `normal`: The normal of the mirror, which must have unit length.
+///
+/// # Returns
+///
+/// A new vector of the same length as `vec`.
+pub fn reflect<const F: usize, T: Ring>(vec: &[T; F], normal: &[T; F]) -> [T; F] {
+    let along = dot(vec, normal);
+    sub(vec, &scale(normal, &(along + along)))
+}
+
+/// Vector Refraction
+///
+/// Bend the unit vector `incident` as it passes through a surface with unit
+/// normal `normal` into a medium, following Snell's law. `eta` is the ratio
+/// of the refractive index of the medium the ray leaves to that of the one
+/// it enters, and `normal` should face against `incident`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::refract;
+///
+/// // Straight through the surface, the direction does not change.
+/// assert_eq!(refract(&[0.0, -1.0], &[0.0, 1.0], 1.5), Some([0.0, -1.0]));
+/// // Leaving glass at a grazing angle reflects the ray entirely.
+/// assert_eq!(refract(&[0.8, -0.6], &[0.0, 1.0], 1.5), None);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `incident`: The direction of the incoming ray, which must have unit
+///   length.
+/// - `normal`: The normal of the surface, which must have unit length.
+/// - `eta`: The ratio of the refractive indices.
+///
+/// # Returns
+///
+/// A new unit vector in the direction of the refracted ray, or `None` on
+/// total internal reflection.
+pub fn refract<const F: usize, T: RealField>(incident: &[T; F], normal: &[T; F], eta: T) -> Option<[T; F]> {
+    let cosine = dot(normal, incident);
+    let k = T::one() - eta * eta * (T::one() - cosine * cosine);
+    if k < T::zero() {
+        return None;
+    }
+    Some(sub(&scale(incident, &eta), &scale(normal, &(eta * cosine + k.sqrt()))))
+}
+
+/// Angle Between Vectors
+///
+/// The unsigned angle between two vectors, in `[0, π]`. Uses Kahan's
+/// formula on the unit vectors, which stays accurate for nearly parallel and
+/// nearly opposite vectors where the arccosine of the normalized dot product
+/// does not, and for vectors of any magnitude.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::angle_between;
+///
+/// let angle = angle_between(&[1.0, 0.0, 0.0], &[0.0, 2.0, 0.0]).unwrap();
+/// assert!((angle - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if either vector is the zero vector.
+///
+/// # Returns
+///
+/// The angle in radians.
+pub fn angle_between<const F: usize, T: RealField>(vec_a: &[T; F], vec_b: &[T; F]) -> Result<T, VectorError> {
+    let (length_a, length_b) = (norm_l2(vec_a), norm_l2(vec_b));
+    if length_a.is_zero() || length_b.is_zero() {
+        return Err(VectorError::ZeroVector);
+    }
+    let a = vec_a.map(|value| value / length_a);
+    let b = vec_b.map(|value| value / length_b);
+    let difference = norm_l2(&sub(&a, &b));
+    let sum: [T; F] = core::array::from_fn(|i| a[i] + b[i]);
+    Ok(difference.atan2(norm_l2(&sum)) * T::from_f64(2.0))
+}
+
+/// Signed 2D Angle
+///
+/// The angle to turn `vec_a` through to point along `vec_b`, in `(-π, π]`,
+/// positive when `vec_b` is counter-clockwise from `vec_a`. The angle
+/// involving a zero vector is zero.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::signed_angle_2d;
+///
+/// let angle = signed_angle_2d(&[0.0, 1.0], &[1.0, 0.0]);
+/// assert_eq!(angle, -core::f64::consts::FRAC_PI_2);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `vec_a`: The vector to turn from.
+/// - `vec_b`: The vector to turn to.
+///
+/// # Returns
+///
+/// The signed angle in radians.
+pub fn signed_angle_2d<T: RealField>(vec_a: &[T; 2], vec_b: &[T; 2]) -> T {
+    perp_dot(vec_a, vec_b).atan2(dot(vec_a, vec_b))
+}
+
+/// Orthonormal Basis
+///
+/// Two unit vectors that complete the unit vector `normal` to a
+/// right-handed orthonormal basis, so that `b1 × b2 = normal`. Uses the
+/// branchless construction of Duff et al., "Building an Orthonormal Basis,
+/// Revisited" (2017), a fix of Frisvad's method that stays accurate when
+/// `normal` points along negative `z`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{cross, orthonormal_basis};
+///
+/// let (b1, b2) = orthonormal_basis(&[0.0, 0.0, 1.0]);
+/// assert_eq!((b1, b2), ([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]));
+/// assert_eq!(cross(&b1, &b2), [0.0, 0.0, 1.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `normal`: The first basis vector, which must have unit length.
+///
+/// # Returns
+///
+/// The other two basis vectors.
+pub fn orthonormal_basis<T: Field + PartialOrd>(normal: &[T; 3]) -> ([T; 3], [T; 3]) {
+    let [x, y, z] = *normal;
+    let sign = if z < T::zero() { -T::one() } else { T::one() };
+    let a = -(sign + z).recip();
+    let b = x * y * a;
+    ([T::one() + sign * x * x * a, sign * b, -sign * x], [b, sign + y * y * a, -y])
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{cross, normalize};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_projection_and_rejection() {
+        let v = [3.0, -1.0, 2.0];
+        let onto = [1.0, 1.0, 1.0];
+        let projection = project_onto(&v, &onto).unwrap();
+        let rejection = reject_from(&v, &onto).unwrap();
+        assert_close(&projection, &[4.0 / 3.0; 3]);
+        assert_close(&crate::add(&projection, &rejection), &v);
+        assert!(dot(&rejection, &onto).abs() < 1e-12);
+        assert_eq!(reject_from(&v, &[0.0; 3]), Err(VectorError::ZeroVector));
+    }
+
+    #[test]
+    fn test_reflection_and_refraction() {
+        let normal = [0.0, 1.0, 0.0];
+        assert_eq!(reflect(&[1.0, -2.0, 3.0], &normal), [1.0, 2.0, 3.0]);
+        // Snell's law: the sines of the angles from the normal have ratio eta.
+        let incident = normalize(&[1.0_f64, -1.0, 0.0]);
+        let eta = 1.0 / 1.33;
+        let refracted = refract(&incident, &normal, eta).unwrap();
+        assert!((norm_l2(&refracted) - 1.0).abs() < 1e-12);
+        assert!((refracted[0] - eta * incident[0]).abs() < 1e-12);
+        assert!(refracted[1] < 0.0);
+        assert_eq!(refract(&incident, &normal, 1.5), None);
+        assert_close(&refract(&incident, &normal, 1.0).unwrap(), &incident);
+    }
+
+    #[test]
+    fn test_angles() {
+        let angle = angle_between(&[1.0, 0.0], &[1.0, 1.0]).unwrap();
+        assert!((angle - core::f64::consts::FRAC_PI_4).abs() < 1e-15);
+        assert_eq!(angle_between(&[1.0, 2.0, 3.0], &[-2.0, -4.0, -6.0]), Ok(core::f64::consts::PI));
+        // Nearly parallel vectors, where the arccosine of the dot product is zero.
+        let tiny = angle_between(&[1.0_f64, 0.0], &[1.0, 1e-10]).unwrap();
+        assert!((tiny - 1e-10).abs() < 1e-24);
+        assert_eq!(angle_between(&[0.0, 0.0], &[1.0, 0.0]), Err(VectorError::ZeroVector));
+        // The angle does not depend on the magnitudes, even extreme ones.
+        let expected = 0.1_f64.atan();
+        for scale in [1e200, 1e-200, 1e-300] {
+            let angle = angle_between(&[scale, scale / 10.0], &[scale, 0.0]).unwrap();
+            assert!((angle - expected).abs() < 1e-15, "{angle} != {expected} at {scale}");
+        }
+        let angle = angle_between(&[1e300, 0.0], &[-1e-300, 1e-300]).unwrap();
+        assert!((angle - 3.0 * core::f64::consts::FRAC_PI_4).abs() < 1e-15);
+        assert!((signed_angle_2d(&[1.0, 1.0], &[-1.0, 1.0]) - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert_eq!(signed_angle_2d(&[1.0, 0.0], &[-1.0, 0.0]), core::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_orthonormal_basis() {
+        let normals = [[0.0_f64, 0.0, 1.0], [0.0, 0.0, -1.0], [1.0, 0.0, 0.0], normalize(&[1.0, -2.0, 3.0]), normalize(&[0.3, 0.2, -1e-9])];
+        for normal in &normals {
+            let (b1, b2) = orthonormal_basis(normal);
+            assert!((norm_l2(&b1) - 1.0).abs() < 1e-12);
+            assert!((norm_l2(&b2) - 1.0).abs() < 1e-12);
+            assert!(dot(&b1, &b2).abs() < 1e-12);
+            assert!(dot(&b1, normal).abs() < 1e-12);
+            assert_close(&cross(&b1, &b2), normal);
+        }
+    }
+}
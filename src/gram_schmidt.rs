@@ -0,0 +1,212 @@
+use crate::{norm::scaled_norm, RealField, VectorError};
+#[cfg(feature = "alloc")]
+use crate::{DVector, ShapeError};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Gram–Schmidt Orthonormalization
+///
+/// An orthonormal basis for the span of `vectors`, where the `j`-th output
+/// spans the same space as the first `j + 1` inputs. Uses modified
+/// Gram–Schmidt and orthogonalizes each vector a second time, so the result
+/// stays orthonormal to working precision even when the inputs are nearly
+/// dependent.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{gram_schmidt, VectorError};
+///
+/// let basis = gram_schmidt(&[[3.0, 4.0, 0.0], [1.0, 0.0, 1.0]]).unwrap();
+/// assert_eq!(basis[0], [0.6, 0.8, 0.0]);
+/// assert!(basis[0].iter().zip(&basis[1]).map(|(a, b)| a * b).sum::<f64>().abs() < 1e-15);
+///
+/// let dependent = gram_schmidt(&[[1.0, 2.0], [-2.0, -4.0]]);
+/// assert_eq!(dependent, Err(VectorError::LinearlyDependent { index: 1 }));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The length of the vectors.
+/// - `K`: The number of vectors.
+///
+/// # Arguments
+///
+/// - `vectors`: The vectors to orthonormalize, in order.
+///
+/// # Errors
+///
+/// Returns [`VectorError::LinearlyDependent`] with the index of the first
+/// vector that lies in the span of the ones before it, including any zero
+/// vector.
+///
+/// # Returns
+///
+/// The `K` orthonormal vectors.
+pub fn gram_schmidt<const N: usize, const K: usize, T: RealField>(
+    vectors: &[[T; N]; K],
+) -> Result<[[T; N]; K], VectorError> {
+    let mut basis = *vectors;
+    gram_schmidt_in_place(basis.as_flattened_mut(), N, K)?;
+    Ok(basis)
+}
+
+/// Dynamic Gram–Schmidt Orthonormalization
+///
+/// The dynamically sized counterpart of [`gram_schmidt`], for a set of
+/// vectors whose length or count is only known at run time.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{try_gram_schmidt, DVector};
+///
+/// let vectors = [DVector::from([0.0, 2.0]), DVector::from([1.0, 1.0])];
+/// let basis = try_gram_schmidt(&vectors).unwrap();
+/// assert_eq!(basis[0].as_slice(), &[0.0, 1.0]);
+/// assert_eq!(basis[1].as_slice(), &[1.0, 0.0]);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `vectors`: The vectors to orthonormalize, in order.
+///
+/// # Errors
+///
+/// Returns [`VectorError::DimensionMismatch`] if the vectors differ in
+/// length, or [`VectorError::LinearlyDependent`] with the index of the first
+/// vector that lies in the span of the ones before it.
+///
+/// # Returns
+///
+/// A new vector of orthonormal vectors, one for each input.
+#[cfg(feature = "alloc")]
+pub fn try_gram_schmidt<T: RealField>(vectors: &[DVector<T>]) -> Result<Vec<DVector<T>>, VectorError> {
+    let n = vectors.first().map_or(0, DVector::len);
+    if let Some(other) = vectors.iter().find(|vector| vector.len() != n) {
+        return Err(ShapeError { left: (n, 1), right: (other.len(), 1) }.into());
+    }
+    let mut data: Vec<T> = vectors.iter().flat_map(|vector| vector.iter().copied()).collect();
+    gram_schmidt_in_place(&mut data, n, vectors.len())?;
+    Ok((0..vectors.len()).map(|j| DVector::from_slice(&data[j * n..(j + 1) * n])).collect())
+}
+
+/// Orthonormalize the `k` rows of length `n` stored back to back in `data`.
+///
+/// Each row has its components along the earlier rows removed twice, which
+/// restores the orthogonality that a single modified Gram–Schmidt pass loses
+/// to cancellation. A row whose remaining length falls to rounding level
+/// relative to its original length is reported as dependent rather than
+/// normalized into noise.
+pub(crate) fn gram_schmidt_in_place<T: RealField>(data: &mut [T], n: usize, k: usize) -> Result<(), VectorError> {
+    let factor = T::epsilon() * T::from_f64(n.max(k) as f64);
+    for j in 0..k {
+        let (done, rest) = data.split_at_mut(j * n);
+        let row = &mut rest[..n];
+        let original = scaled_norm(row.iter().copied());
+        for _ in 0..2 {
+            for i in 0..j {
+                let basis = &done[i * n..(i + 1) * n];
+                let along = basis.iter().zip(row.iter()).fold(T::zero(), |acc, (&b, &r)| acc + b * r);
+                for (r, &b) in row.iter_mut().zip(basis) {
+                    *r -= along * b;
+                }
+            }
+        }
+        let remaining = scaled_norm(row.iter().copied());
+        if !remaining.is_finite() || remaining <= original * factor {
+            return Err(VectorError::LinearlyDependent { index: j });
+        }
+        for r in row.iter_mut() {
+            *r /= remaining;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+
+    fn assert_orthonormal(rows: &[&[f64]]) {
+        for (i, a) in rows.iter().enumerate() {
+            for (j, b) in rows.iter().enumerate() {
+                let dot: f64 = a.iter().zip(*b).map(|(x, y)| x * y).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-12, "{rows:?} is not orthonormal");
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthonormal_and_same_span() {
+        let vectors = [[2.0, 1.0, 0.0, -1.0], [1.0, 3.0, 1.0, 0.0], [0.0, 1.0, 4.0, 2.0]];
+        let basis = gram_schmidt(&vectors).unwrap();
+        assert_orthonormal(&[&basis[0], &basis[1], &basis[2]]);
+        // Each input is recovered from its components along the basis so far.
+        for (j, vector) in vectors.iter().enumerate() {
+            let mut rebuilt = [0.0; 4];
+            for q in &basis[..=j] {
+                let along: f64 = q.iter().zip(vector).map(|(a, b)| a * b).sum();
+                for (r, x) in rebuilt.iter_mut().zip(q) {
+                    *r += along * x;
+                }
+            }
+            for (r, x) in rebuilt.iter().zip(vector) {
+                assert!((r - x).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearly_dependent() {
+        // Columns of a Läuchli matrix, where a single pass loses orthogonality.
+        let delta = 1e-7;
+        let vectors = [[1.0, delta, 0.0, 0.0], [1.0, 0.0, delta, 0.0], [1.0, 0.0, 0.0, delta]];
+        let basis = gram_schmidt(&vectors).unwrap();
+        assert_orthonormal(&[&basis[0], &basis[1], &basis[2]]);
+    }
+
+    #[test]
+    fn test_dependent() {
+        assert_eq!(
+            gram_schmidt(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [3.0, -2.0, 0.0]]),
+            Err(VectorError::LinearlyDependent { index: 2 })
+        );
+        assert_eq!(gram_schmidt(&[[0.0, 0.0], [1.0, 0.0]]), Err(VectorError::LinearlyDependent { index: 0 }));
+        assert_eq!(
+            gram_schmidt(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            Err(VectorError::LinearlyDependent { index: 2 })
+        );
+        let no_vectors: [[f64; 3]; 0] = [];
+        assert_eq!(gram_schmidt(&no_vectors), Ok(no_vectors));
+    }
+
+    #[test]
+    fn test_extreme_magnitudes() {
+        let huge = gram_schmidt(&[[1e200, 1e200], [1.0, 0.0]]).unwrap();
+        assert_orthonormal(&[&huge[0], &huge[1]]);
+        let tiny = gram_schmidt(&[[1e-200, 0.0], [0.0, 1.0]]).unwrap();
+        assert_eq!(tiny, [[1.0, 0.0], [0.0, 1.0]]);
+        let subnormal = gram_schmidt(&[[3e-320, 4e-320, 0.0], [0.0, 0.0, 1e300]]).unwrap();
+        assert_orthonormal(&[&subnormal[0], &subnormal[1]]);
+        assert_eq!(gram_schmidt(&[[1e-200, 0.0], [-2e-200, 0.0]]), Err(VectorError::LinearlyDependent { index: 1 }));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic() {
+        let vectors = [DVector::from([1.0, 1.0, 0.0]), DVector::from([1.0, 0.0, 1.0])];
+        let basis = try_gram_schmidt(&vectors).unwrap();
+        assert_orthonormal(&[basis[0].as_slice(), basis[1].as_slice()]);
+        let mismatched = [DVector::from([1.0, 0.0]), DVector::from([1.0, 0.0, 1.0])];
+        let expected = ShapeError { left: (2, 1), right: (3, 1) };
+        assert_eq!(try_gram_schmidt(&mismatched), Err(VectorError::DimensionMismatch(expected)));
+        let dependent = [DVector::from([1.0, 2.0]), DVector::from([2.0, 4.0])];
+        assert_eq!(try_gram_schmidt(&dependent), Err(VectorError::LinearlyDependent { index: 1 }));
+    }
+}
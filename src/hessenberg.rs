@@ -0,0 +1,112 @@
+use crate::RealField;
+
+/// Hessenberg Decomposition
+///
+/// The factorization `A = Q H Qᵀ` of a square matrix, where `Q` is
+/// orthogonal and `H` is upper Hessenberg: zero below its first
+/// subdiagonal. `H` has the same eigenvalues as `A` and is the starting
+/// point of the QR algorithm. Computed with Householder reflections.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Hessenberg;
+///
+/// let hessenberg = Hessenberg::new(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+/// assert_eq!(hessenberg.h()[2][0], 0.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hessenberg<T, const N: usize> {
+    h: [[T; N]; N],
+    q: [[T; N]; N],
+}
+
+impl<T: RealField, const N: usize> Hessenberg<T, N> {
+    /// Reduce `matrix`.
+    pub fn new(matrix: &[[T; N]; N]) -> Self {
+        let mut h = *matrix;
+        let mut q = crate::identity();
+        let mut scratch = [T::zero(); N];
+        hessenberg_in_place(h.as_flattened_mut(), Some(q.as_flattened_mut()), &mut scratch, N);
+        Self { h, q }
+    }
+
+    /// The upper Hessenberg factor `H`.
+    pub fn h(&self) -> [[T; N]; N] {
+        self.h
+    }
+
+    /// The orthogonal factor `Q`.
+    pub fn q(&self) -> [[T; N]; N] {
+        self.q
+    }
+}
+
+/// Reduce the `n` by `n` row-major matrix in `a` to upper Hessenberg form,
+/// accumulating the reflections into `q` if given, which must start as the
+/// identity. `v` is scratch space of length `n`.
+pub(crate) fn hessenberg_in_place<T: RealField>(a: &mut [T], mut q: Option<&mut [T]>, v: &mut [T], n: usize) {
+    for k in 0..n.saturating_sub(2) {
+        let alpha = a[(k + 1) * n + k];
+        let tail = (k + 2..n).fold(T::zero(), |sum, i| sum + a[i * n + k] * a[i * n + k]);
+        if tail.is_zero() {
+            continue;
+        }
+        let length = (alpha * alpha + tail).sqrt();
+        let beta = if alpha < T::zero() { length } else { -length };
+        let tau = (beta - alpha) / beta;
+        let scale = (alpha - beta).recip();
+        v[k + 1] = T::one();
+        for i in k + 2..n {
+            v[i] = a[i * n + k] * scale;
+        }
+        // H = I - tau v vᵀ, applied from the left and then the right.
+        for j in k..n {
+            let w = (k + 1..n).fold(T::zero(), |sum, i| sum + v[i] * a[i * n + j]) * tau;
+            for i in k + 1..n {
+                a[i * n + j] -= v[i] * w;
+            }
+        }
+        for row in a.chunks_exact_mut(n).chain(q.iter_mut().flat_map(|q| q.chunks_exact_mut(n))) {
+            let w = (k + 1..n).fold(T::zero(), |sum, j| sum + row[j] * v[j]) * tau;
+            for j in k + 1..n {
+                row[j] -= w * v[j];
+            }
+        }
+        a[(k + 1) * n + k] = beta;
+        for i in k + 2..n {
+            a[i * n + k] = T::zero();
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{identity, matrix_multiply, transpose};
+
+    fn assert_close<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) {
+        for (x, y) in a.as_flattened().iter().zip(b.as_flattened()) {
+            assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let matrix: [[f64; 5]; 5] = crate::from_fn(|i, j| ((i * 7 + j * 3) % 5) as f64 - (i as f64) * 0.5);
+        let hessenberg = Hessenberg::new(&matrix);
+        let (h, q) = (hessenberg.h(), hessenberg.q());
+        assert_close(&matrix_multiply(&transpose(&q), &q), &identity());
+        assert_close(&matrix_multiply(&matrix_multiply(&q, &h), &transpose(&q)), &matrix);
+        for (i, row) in h.iter().enumerate() {
+            assert!(row[..i.saturating_sub(1)].iter().all(|value| *value == 0.0));
+        }
+    }
+}
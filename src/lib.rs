@@ -1,4 +1,72 @@
-use std::{fmt::Debug, ops::{Add, Div, Mul, Sub}};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod cholesky;
+mod complex;
+mod cross;
+mod determinant;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod eigen;
+mod error;
+mod euler;
+mod fallible;
+mod geometry;
+mod gram_schmidt;
+mod hessenberg;
+mod lu;
+mod matrix;
+mod matrix_ops;
+mod norm;
+mod num;
+mod overflow;
+mod qr;
+mod quaternion;
+mod svd;
+mod transform;
+mod vector;
+
+pub use cholesky::{Cholesky, Ldlt};
+pub use complex::Complex;
+pub use cross::{cross, perp_dot, perpendicular, scalar_triple_product, vector_triple_product};
+pub use determinant::{adjugate, cofactor, determinant, inverse};
+#[cfg(feature = "alloc")]
+pub use dynamic::{DMatrix, DVector};
+#[cfg(feature = "alloc")]
+pub use eigen::DSymmetricEigen;
+pub use eigen::{Eigen, SymmetricEigen};
+pub use error::{ShapeError, VectorError};
+pub use euler::{euler_to_rotation_matrix, rotation_matrix_to_euler, EulerFrame, EulerOrder};
+#[cfg(feature = "alloc")]
+pub use fallible::{
+    try_add, try_checked_add, try_checked_matrix_vec_multiply, try_checked_sub, try_matrix_multiply, try_matrix_vec_multiply,
+    try_sub,
+};
+pub use geometry::{angle_between, orthonormal_basis, project_onto, reflect, refract, reject_from, signed_angle_2d};
+pub use gram_schmidt::gram_schmidt;
+#[cfg(feature = "alloc")]
+pub use gram_schmidt::try_gram_schmidt;
+pub use hessenberg::Hessenberg;
+pub use lu::Lu;
+pub use matrix::Matrix;
+pub use matrix_ops::{conjugate_transpose, diagonal, from_fn, identity, matrix_add, matrix_scale, matrix_sub, ones, trace, transpose, zeros};
+pub use norm::{distance, dot, inner_product, norm_inf, norm_l1, norm_l2, norm_p, normalize, squared_length, try_normalize};
+pub use num::{Abs, ComplexField, Elimination, Field, Integer, One, RealField, Ring, Scalar, Zero};
+pub use overflow::{
+    checked_add, checked_matrix_vec_multiply, checked_scale, checked_sub, overflowing_add, overflowing_matrix_vec_multiply,
+    overflowing_scale, overflowing_sub, saturating_add, saturating_matrix_vec_multiply, saturating_scale, saturating_sub,
+    wrapping_add, wrapping_matrix_vec_multiply, wrapping_scale, wrapping_sub,
+};
+pub use qr::{lstsq, ColPivQr, Qr};
+pub use quaternion::Quaternion;
+pub use svd::{pseudo_inverse, Svd};
+pub use transform::{
+    look_at, orthographic, perspective, rotation_2d, rotation_3d, rotation_x, rotation_y, rotation_z, scaling_2d, scaling_3d, shear_2d,
+    shear_3d, transform_direction_2d, transform_direction_3d, transform_point_2d, transform_point_3d, translation_2d, translation_3d,
+};
+pub use vector::Vector;
 
 /// Vector Subtraction
 ///
@@ -15,10 +83,6 @@ use std::{fmt::Debug, ops::{Add, Div, Mul, Sub}};
 /// assert_eq!(sub(&a, &b), expected);
 /// ```
 ///
-/// # Panics
-///
-/// This function will panic if the lengths of the two vectors are not equal.
-///
 /// # Type Parameters
 ///
 /// - `F`: The length of the vectors.
@@ -31,17 +95,8 @@ use std::{fmt::Debug, ops::{Add, Div, Mul, Sub}};
 /// # Returns
 ///
 /// A new vector containing the difference of the two input vectors.
-pub fn sub<'a, 'b, const F: usize, T: Sub<Output = T> + Div<Output = T> + Debug + Copy>(vec_a: &'a [T; F], vec_b: &'b [T; F]) -> [T; F]
-where 
-    &'a T: Sub<&'a T>
-{
-    vec_a
-        .iter()
-        .zip(vec_b.iter())
-        .map(|(a, b)| *a - *b)
-        .collect::<Vec<T>>()
-        .try_into()
-        .unwrap()
+pub fn sub<const F: usize, T: Ring>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| fallible::sub_element(vec_a[i], vec_b[i]))
 }
 
 /// Vector Addition
@@ -59,10 +114,6 @@ where
 /// assert_eq!(add(&a, &b), expected);
 /// ```
 ///
-/// # Panics
-///
-/// This function will panic if the lengths of the two vectors are not equal.
-///
 /// # Type Parameters
 ///
 /// - `F`: The length of the vectors.
@@ -75,17 +126,8 @@ where
 /// # Returns
 ///
 /// A new vector containing the sum of the two input vectors.
-pub fn add<'a, 'b, const F: usize, T: Add<Output = T> + Div<Output = T> + Debug + Copy>(vec_a: &'a [T; F], vec_b: &'b [T; F]) -> [T; F]
-where 
-    &'a T: Add<&'a T>
-{
-    vec_a
-        .iter()
-        .zip(vec_b.iter())
-        .map(|(a, b)| *a + *b)
-        .collect::<Vec<T>>()
-        .try_into()
-        .unwrap()
+pub fn add<const F: usize, T: Ring>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| fallible::add_element(vec_a[i], vec_b[i]))
 }
 
 /// Vector Scaling
@@ -114,27 +156,21 @@ where
 /// # Returns
 ///
 /// A new vector containing the scaled values of the input vector.
-pub fn scale<'a, const F: usize, T: Mul<Output = T> + Div<Output = T> + Debug + Copy>(vec: &'a [T; F], scalar: &T) -> [T; F]
-where 
-    &'a T: Mul<&'a T>
-{
-    vec.iter()
-        .map(|a| *a * *scalar)
-        .collect::<Vec<T>>()
-        .try_into()
-        .unwrap()
+pub fn scale<const F: usize, T: Ring>(vec: &[T; F], scalar: &T) -> [T; F] {
+    core::array::from_fn(|i| vec[i] * *scalar)
 }
 
 /// Matrix Vector Multiplication
 ///
-/// Multiply the vector by the matrix.
+/// Multiply the vector by the matrix. The matrix is stored in row-major
+/// order, so `matrix[i]` is the `i`-th row.
 ///
 /// # Examples
 ///
 /// ```
 /// use vector_operations::matrix_vec_multiply;
 ///
-/// let matrix = [[1, 2], [-3, 4]];
+/// let matrix = [[1, -3], [2, 4]];
 /// let vector = [5, 7];
 /// let expected = [-16, 38];
 /// assert_eq!(matrix_vec_multiply(&matrix, &vector), expected);
@@ -153,20 +189,89 @@ where
 /// # Returns
 ///
 /// A new vector containing the result of multiplying the matrix and vector.
-pub fn matrix_vec_multiply<'a, 'b, const M: usize, const N: usize, T: Mul<Output = T> + Add<Output = T> + Debug + Copy + Default + std::ops::AddAssign>(
-    matrix: &'a [[T; N]; M],
-    vector: &'b [T; N],
+pub fn matrix_vec_multiply<const M: usize, const N: usize, T: Ring>(
+    matrix: &[[T; N]; M],
+    vector: &[T; N],
 ) -> [T; M] {
-    let zero: T = T::default();
-    let mut result: [T; M] = [zero; M];
-    for i in 0..M {
-        for j in 0..N {
-            result[i] += matrix[j][i] * vector[j];
+    core::array::from_fn(|i| matrix[i].iter().zip(vector).fold(T::zero(), |sum, (a, b)| fallible::multiply_add(sum, *a, *b)))
+}
+
+/// Block size used by [`matrix_multiply`] once any dimension exceeds it.
+const BLOCK_SIZE: usize = 32;
+
+/// Matrix Multiplication
+///
+/// Multiply two matrices together. Both matrices are stored in row-major
+/// order. Matrices with a dimension larger than 32 are multiplied in cache
+/// sized blocks.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::matrix_multiply;
+///
+/// let a = [[1, 2, 3], [4, 5, 6]];
+/// let b = [[1, 0], [0, 1], [1, -1]];
+/// let expected = [[4, -1], [10, -1]];
+/// assert_eq!(matrix_multiply(&a, &b), expected);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the first matrix.
+/// - `N`: The number of columns in the first matrix and rows in the second.
+/// - `P`: The number of columns in the second matrix.
+///
+/// # Arguments
+///
+/// - `matrix_a`: The left matrix.
+/// - `matrix_b`: The right matrix.
+///
+/// # Returns
+///
+/// A new `M` by `P` matrix containing the product of the two input matrices.
+pub fn matrix_multiply<const M: usize, const N: usize, const P: usize, T: Ring>(
+    matrix_a: &[[T; N]; M],
+    matrix_b: &[[T; P]; N],
+) -> [[T; P]; M] {
+    let mut result: [[T; P]; M] = [[T::zero(); P]; M];
+    if M <= BLOCK_SIZE && N <= BLOCK_SIZE && P <= BLOCK_SIZE {
+        multiply_block(matrix_a, matrix_b, &mut result, (0, M), (0, N), (0, P));
+        return result;
+    }
+    for i in (0..M).step_by(BLOCK_SIZE) {
+        let rows = (i, (i + BLOCK_SIZE).min(M));
+        for k in (0..N).step_by(BLOCK_SIZE) {
+            let inner = (k, (k + BLOCK_SIZE).min(N));
+            for j in (0..P).step_by(BLOCK_SIZE) {
+                let columns = (j, (j + BLOCK_SIZE).min(P));
+                multiply_block(matrix_a, matrix_b, &mut result, rows, inner, columns);
+            }
         }
     }
     result
 }
 
+/// Accumulate the product of one block of `matrix_a` and `matrix_b` into
+/// `result`. Each range is a half-open `(start, end)` pair.
+fn multiply_block<const M: usize, const N: usize, const P: usize, T: Ring>(
+    matrix_a: &[[T; N]; M],
+    matrix_b: &[[T; P]; N],
+    result: &mut [[T; P]; M],
+    rows: (usize, usize),
+    inner: (usize, usize),
+    columns: (usize, usize),
+) {
+    for i in rows.0..rows.1 {
+        for k in inner.0..inner.1 {
+            let a = matrix_a[i][k];
+            for j in columns.0..columns.1 {
+                result[i][j] += a * matrix_b[k][j];
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,9 +305,45 @@ mod tests {
 
     #[test]
     fn test_matrix_vec_multiply() {
-        let matrix = [[1, 2], [-3, 4]];
+        let matrix = [[1, -3], [2, 4]];
         let vector = [5, 7];
         let expected = [-16, 38];
         assert_eq!(matrix_vec_multiply(&matrix, &vector), expected);
     }
+
+    #[test]
+    fn test_matrix_vec_multiply_non_square() {
+        let matrix = [[1, 2, 3], [4, 5, 6]];
+        let vector = [1, 0, -1];
+        let expected = [-2, -2];
+        assert_eq!(matrix_vec_multiply(&matrix, &vector), expected);
+    }
+
+    #[test]
+    fn test_matrix_multiply() {
+        let a = [[1, 2, 3], [4, 5, 6]];
+        let b = [[1, 0], [0, 1], [1, -1]];
+        let expected = [[4, -1], [10, -1]];
+        assert_eq!(matrix_multiply(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_matrix_multiply_blocked() {
+        const S: usize = 70;
+        let mut a = [[0i64; S]; S];
+        let mut b = [[0i64; S]; S];
+        for i in 0..S {
+            for j in 0..S {
+                a[i][j] = (i * S + j) as i64 % 7 - 3;
+                b[i][j] = (i + 2 * j) as i64 % 5 - 2;
+            }
+        }
+        let result = matrix_multiply(&a, &b);
+        for i in 0..S {
+            for j in 0..S {
+                let expected: i64 = (0..S).map(|k| a[i][k] * b[k][j]).sum();
+                assert_eq!(result[i][j], expected);
+            }
+        }
+    }
 }
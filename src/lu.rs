@@ -0,0 +1,309 @@
+use crate::{ComplexField, Field, RealField, VectorError, Zero};
+
+/// LU Decomposition
+///
+/// The factorization `P A = L U` of a square matrix, where `P` is a row
+/// permutation chosen by partial pivoting, `L` is unit lower triangular and
+/// `U` is upper triangular. Factor once, then solve `A x = b` for as many
+/// right-hand sides as needed.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Lu;
+///
+/// let lu = Lu::new(&[[2.0, 1.0], [1.0, 3.0]]);
+/// assert_eq!(lu.solve(&[3.0, 4.0]), Ok([1.0, 1.0]));
+/// assert_eq!(lu.determinant(), 5.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The number of rows and columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    lu: [[T; N]; N],
+    permutation: [usize; N],
+    odd: bool,
+    singular: bool,
+}
+
+impl<T: ComplexField, const N: usize> Lu<T, N> {
+    /// Factor `matrix`. A singular matrix still factors; [`Lu::solve`] and
+    /// [`Lu::inverse`] report it, and [`Lu::determinant`] is zero or close
+    /// to it.
+    pub fn new(matrix: &[[T; N]; N]) -> Self {
+        let mut lu = *matrix;
+        let mut permutation = [0; N];
+        let (odd, singular) = lu_in_place(lu.as_flattened_mut(), &mut permutation, N);
+        Self { lu, permutation, odd, singular }
+    }
+
+    /// The unit lower triangular factor `L`.
+    pub fn l(&self) -> [[T; N]; N] {
+        crate::from_fn(|i, j| match i.cmp(&j) {
+            core::cmp::Ordering::Greater => self.lu[i][j],
+            core::cmp::Ordering::Equal => T::one(),
+            core::cmp::Ordering::Less => T::zero(),
+        })
+    }
+
+    /// The upper triangular factor `U`.
+    pub fn u(&self) -> [[T; N]; N] {
+        crate::from_fn(|i, j| if i <= j { self.lu[i][j] } else { T::zero() })
+    }
+
+    /// The row permutation: row `i` of `P A` is row `permutation()[i]` of
+    /// `A`.
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    /// The permutation as a matrix `P`, so that `P A = L U`.
+    pub fn permutation_matrix(&self) -> [[T; N]; N] {
+        crate::from_fn(|i, j| if self.permutation[i] == j { T::one() } else { T::zero() })
+    }
+
+    /// Whether a pivot was zero relative to the size of the largest element
+    /// of the matrix.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// The determinant of the matrix: the product of the pivots, negated if
+    /// the permutation is odd.
+    pub fn determinant(&self) -> T {
+        let product = (0..N).fold(T::one(), |product, i| product * self.lu[i][i]);
+        if self.odd {
+            -product
+        } else {
+            product
+        }
+    }
+
+    /// Solve `A x = b`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if the matrix is singular.
+    pub fn solve(&self, b: &[T; N]) -> Result<[T; N], VectorError> {
+        self.check_singular()?;
+        let mut x = *b;
+        lu_solve(self.lu.as_flattened(), &self.permutation, b, &mut x, N, 1);
+        Ok(x)
+    }
+
+    /// Solve `A X = B` for every column of `B` at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if the matrix is singular.
+    pub fn solve_many<const K: usize>(&self, b: &[[T; K]; N]) -> Result<[[T; K]; N], VectorError> {
+        self.check_singular()?;
+        let mut x = *b;
+        lu_solve(self.lu.as_flattened(), &self.permutation, b.as_flattened(), x.as_flattened_mut(), N, K);
+        Ok(x)
+    }
+
+    /// The inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if the matrix is singular.
+    pub fn inverse(&self) -> Result<[[T; N]; N], VectorError> {
+        self.solve_many(&crate::identity())
+    }
+
+    fn check_singular(&self) -> Result<(), VectorError> {
+        if self.singular {
+            Err(VectorError::Singular)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Factor the `n` by `n` row-major matrix in `a` into its combined `L` and
+/// `U` factors, writing the row order to `permutation`. Returns whether the
+/// permutation is odd and whether the matrix is singular.
+pub(crate) fn lu_in_place<T: ComplexField>(a: &mut [T], permutation: &mut [usize], n: usize) -> (bool, bool) {
+    for (i, p) in permutation.iter_mut().enumerate() {
+        *p = i;
+    }
+    let scale = a.iter().fold(T::Real::zero(), |max, value| max.max(value.modulus()));
+    let tolerance = scale * T::Real::epsilon() * T::Real::from_f64(n as f64);
+    let odd = eliminate_in_place(a, Some(permutation), n, T::modulus);
+    let singular = (0..n).any(|k| {
+        let magnitude = a[k * n + k].modulus();
+        magnitude <= tolerance || !magnitude.is_finite()
+    });
+    (odd, singular)
+}
+
+/// Gaussian elimination of the `n` by `n` row-major matrix in `a`, swapping
+/// in the row whose pivot is largest by `magnitude` at each step and storing
+/// the multipliers below the diagonal. Records the row swaps in
+/// `permutation` when given, and returns whether their number is odd.
+pub(crate) fn eliminate_in_place<T: Field, R: PartialOrd>(
+    a: &mut [T],
+    mut permutation: Option<&mut [usize]>,
+    n: usize,
+    magnitude: impl Fn(T) -> R,
+) -> bool {
+    let mut odd = false;
+    for k in 0..n {
+        let pivot_row = (k..n)
+            .max_by(|&x, &y| magnitude(a[x * n + k]).partial_cmp(&magnitude(a[y * n + k])).unwrap_or(core::cmp::Ordering::Equal))
+            .unwrap_or(k);
+        if pivot_row != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot_row * n + j);
+            }
+            if let Some(permutation) = permutation.as_deref_mut() {
+                permutation.swap(k, pivot_row);
+            }
+            odd = !odd;
+        }
+        let pivot = a[k * n + k];
+        if pivot.is_zero() {
+            continue;
+        }
+        for i in k + 1..n {
+            let factor = a[i * n + k] / pivot;
+            a[i * n + k] = factor;
+            for j in k + 1..n {
+                let upper = a[k * n + j];
+                a[i * n + j] -= factor * upper;
+            }
+        }
+    }
+    odd
+}
+
+/// The determinant of the `n` by `n` row-major matrix in `a`, which is
+/// overwritten by its LU factors, choosing pivots by `magnitude`.
+pub(crate) fn lu_determinant<T: Field, R: PartialOrd>(a: &mut [T], n: usize, magnitude: impl Fn(T) -> R) -> T {
+    let odd = eliminate_in_place(a, None, n, magnitude);
+    let product = (0..n).fold(T::one(), |product, k| product * a[k * n + k]);
+    if odd {
+        -product
+    } else {
+        product
+    }
+}
+
+/// Solve `L U X = P B` for the `n` by `k` row-major right-hand side `b`,
+/// writing the solution to `x`.
+pub(crate) fn lu_solve<T: ComplexField>(lu: &[T], permutation: &[usize], b: &[T], x: &mut [T], n: usize, k: usize) {
+    for (row, &source) in x.chunks_exact_mut(k).zip(permutation) {
+        row.copy_from_slice(&b[source * k..source * k + k]);
+    }
+    for i in 0..n {
+        for j in 0..i {
+            let factor = lu[i * n + j];
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= factor * value;
+            }
+        }
+    }
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            let factor = lu[i * n + j];
+            for c in 0..k {
+                let value = x[j * k + c];
+                x[i * k + c] -= factor * value;
+            }
+        }
+        let pivot = lu[i * n + i].recip();
+        for value in &mut x[i * k..i * k + k] {
+            *value *= pivot;
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{from_fn, identity, matrix_multiply, matrix_vec_multiply};
+
+    fn assert_close<const N: usize>(a: &[f64; N], b: &[f64; N], tolerance: f64) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < tolerance, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_factors() {
+        let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+        let lu = Lu::new(&matrix);
+        assert_eq!(lu.permutation(), &[2, 0, 1]);
+        let pa = matrix_multiply(&lu.permutation_matrix(), &matrix);
+        let product = matrix_multiply(&lu.l(), &lu.u());
+        for (x, y) in pa.iter().zip(&product) {
+            assert_close(x, y, 1e-12);
+        }
+        assert!((lu.determinant() + 3.0).abs() < 1e-12);
+        assert!(!lu.is_singular());
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = [[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+        let lu = Lu::new(&matrix);
+        assert_close(&lu.solve(&[8.0, -11.0, -3.0]).unwrap(), &[2.0, 3.0, -1.0], 1e-12);
+        let many = lu.solve_many(&[[8.0, 1.0], [-11.0, -1.0], [-3.0, 1.0]]).unwrap();
+        assert_close(&[many[0][0], many[1][0], many[2][0]], &[2.0, 3.0, -1.0], 1e-12);
+        assert_close(&matrix_vec_multiply(&matrix, &[many[0][1], many[1][1], many[2][1]]), &[1.0, -1.0, 1.0], 1e-12);
+        let product = matrix_multiply(&matrix, &lu.inverse().unwrap());
+        for (x, y) in product.iter().zip(&identity::<3, f64>()) {
+            assert_close(x, y, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_needs_pivoting() {
+        // Eliminating with the tiny leading element loses the answer entirely.
+        let lu = Lu::new(&[[1e-20, 1.0], [1.0, 1.0]]);
+        assert_close(&lu.solve(&[1.0, 2.0]).unwrap(), &[1.0, 1.0], 1e-12);
+    }
+
+    #[test]
+    fn test_solve_ill_conditioned() {
+        // The 6 by 6 Hilbert matrix has a condition number around 1.5e7.
+        let hilbert: [[f64; 6]; 6] = from_fn(|i, j| 1.0 / (i + j + 1) as f64);
+        let expected = [1.0; 6];
+        let b = matrix_vec_multiply(&hilbert, &expected);
+        assert_close(&Lu::new(&hilbert).solve(&b).unwrap(), &expected, 1e-7);
+        // Nearly parallel rows.
+        let lu = Lu::new(&[[1.0, 1.0], [1.0, 1.0 + 1e-10]]);
+        assert_close(&lu.solve(&[2.0, 2.0 + 1e-10]).unwrap(), &[1.0, 1.0], 1e-5);
+    }
+
+    #[test]
+    fn test_singular() {
+        let lu = Lu::new(&[[1.0_f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert!(lu.is_singular());
+        assert!(lu.determinant().abs() < 1e-12);
+        assert_eq!(lu.solve(&[1.0, 2.0, 3.0]), Err(VectorError::Singular));
+        assert_eq!(Lu::new(&[[0.0_f32; 2]; 2]).inverse(), Err(VectorError::Singular));
+    }
+
+    #[test]
+    fn test_complex() {
+        use crate::Complex;
+        let matrix = [[Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)], [Complex::new(0.0, -1.0), Complex::new(1.0, 3.0)]];
+        let expected = [Complex::new(1.0, -2.0), Complex::new(0.5, 0.5)];
+        let lu = Lu::new(&matrix);
+        let x = lu.solve(&matrix_vec_multiply(&matrix, &expected)).unwrap();
+        for (x, y) in x.iter().zip(&expected) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        // (1 + i)(1 + 3i) - 2(-i) = -2 + 6i.
+        assert!((lu.determinant() - Complex::new(-2.0, 6.0)).modulus() < 1e-12);
+        assert_eq!(crate::inverse(&[[Complex::new(0.0, 2.0)]]), Ok([[Complex::new(0.0, -0.5)]]));
+    }
+}
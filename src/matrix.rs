@@ -0,0 +1,356 @@
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{
+    adjugate, conjugate_transpose, determinant, identity, inverse, matrix_add, matrix_multiply, matrix_scale, matrix_sub, matrix_vec_multiply, trace,
+    transpose, zeros, Cholesky, ColPivQr, ComplexField, Eigen, Elimination, Ldlt, Lu, Qr, RealField, Ring, Svd, SymmetricEigen, Vector, VectorError,
+};
+
+/// Fixed Size Matrix
+///
+/// A matrix with `M` rows and `N` columns stored in row-major order, so the
+/// shape of every operation is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{Matrix, Vector};
+///
+/// let matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+/// let vector = Vector::new([1, 0, -1]);
+/// assert_eq!(matrix * vector, Vector::new([-2, -2]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Matrix<T, const M: usize, const N: usize>([[T; N]; M]);
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a matrix from an array of rows.
+    pub const fn from_rows(rows: [[T; N]; M]) -> Self {
+        Self(rows)
+    }
+
+    /// Borrow the rows of the matrix.
+    pub const fn as_rows(&self) -> &[[T; N]; M] {
+        &self.0
+    }
+
+    /// Consume the matrix and return its rows.
+    pub fn into_rows(self) -> [[T; N]; M] {
+        self.0
+    }
+
+    /// The number of rows in the matrix.
+    pub const fn nrows(&self) -> usize {
+        M
+    }
+
+    /// The number of columns in the matrix.
+    pub const fn ncols(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Create a matrix from an array of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vector_operations::Matrix;
+    ///
+    /// let matrix = Matrix::from_columns([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!(matrix, Matrix::from_rows([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    pub fn from_columns(columns: [[T; M]; N]) -> Self {
+        Self(core::array::from_fn(|i| core::array::from_fn(|j| columns[j][i])))
+    }
+
+    /// The `i`-th row of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `i` is not less than `M`.
+    pub fn row(&self, i: usize) -> Vector<T, N> {
+        Vector::new(self.0[i])
+    }
+
+    /// The `j`-th column of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `j` is not less than `N`.
+    pub fn column(&self, j: usize) -> Vector<T, M> {
+        Vector::new(core::array::from_fn(|i| self.0[i][j]))
+    }
+
+    /// Swap the rows and columns of the matrix. See [`transpose`].
+    pub fn transpose(&self) -> Matrix<T, N, M> {
+        Matrix(transpose(&self.0))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// The matrix whose elements are all zero. See [`zeros`].
+    pub fn zeros() -> Self {
+        Self(zeros())
+    }
+}
+
+impl<T: Ring, const N: usize> Matrix<T, N, N> {
+    /// The identity matrix. See [`identity`].
+    pub fn identity() -> Self {
+        Self(identity())
+    }
+
+    /// The sum of the diagonal elements. See [`trace`].
+    pub fn trace(&self) -> T {
+        trace(&self.0)
+    }
+}
+
+impl<T: Elimination, const N: usize> Matrix<T, N, N> {
+    /// The determinant of the matrix. See [`determinant`].
+    pub fn determinant(&self) -> T {
+        determinant(&self.0)
+    }
+
+    /// The transpose of the cofactor matrix. See [`adjugate`].
+    pub fn adjugate(&self) -> Self {
+        Self(adjugate(&self.0))
+    }
+}
+
+impl<T: ComplexField, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// The conjugate transpose. See [`conjugate_transpose`].
+    pub fn conjugate_transpose(&self) -> Matrix<T, N, M> {
+        Matrix(conjugate_transpose(&self.0))
+    }
+
+    /// The QR decomposition of the matrix. See [`Qr`].
+    pub fn qr(&self) -> Qr<T, M, N> {
+        Qr::new(&self.0)
+    }
+
+    /// The column-pivoted QR decomposition of the matrix. See [`ColPivQr`].
+    pub fn col_piv_qr(&self) -> ColPivQr<T, M, N> {
+        ColPivQr::new(&self.0)
+    }
+
+    /// The singular value decomposition of the matrix. See [`Svd`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if the decomposition does not
+    /// converge.
+    pub fn svd(&self) -> Result<Svd<T, M, N>, VectorError> {
+        Svd::new(&self.0)
+    }
+}
+
+impl<T: ComplexField, const N: usize> Matrix<T, N, N> {
+    /// The inverse of the matrix. See [`inverse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if the matrix is singular.
+    pub fn inverse(&self) -> Result<Self, VectorError> {
+        inverse(&self.0).map(Self)
+    }
+
+    /// The LU decomposition of the matrix. See [`Lu`].
+    pub fn lu(&self) -> Lu<T, N> {
+        Lu::new(&self.0)
+    }
+
+    /// The Cholesky decomposition of the matrix. See [`Cholesky`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if the matrix is not
+    /// positive-definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T, N>, VectorError> {
+        Cholesky::new(&self.0)
+    }
+
+    /// The LDLᴴ decomposition of the matrix. See [`Ldlt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NotPositiveDefinite`] if the matrix is not
+    /// positive semidefinite.
+    pub fn ldlt(&self) -> Result<Ldlt<T, N>, VectorError> {
+        Ldlt::new(&self.0)
+    }
+
+    /// The eigendecomposition of the Hermitian matrix. See
+    /// [`SymmetricEigen`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if the decomposition does not
+    /// converge.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T, N>, VectorError> {
+        SymmetricEigen::new(&self.0)
+    }
+}
+
+impl<T: RealField, const N: usize> Matrix<T, N, N> {
+    /// The complex eigenvalues of the matrix. See [`Eigen`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if the QR algorithm does not
+    /// converge.
+    pub fn eigen(&self) -> Result<Eigen<T, N>, VectorError> {
+        Eigen::new(&self.0)
+    }
+}
+
+impl<T: Default + Copy, const M: usize, const N: usize> Default for Matrix<T, M, N> {
+    fn default() -> Self {
+        Self([[T::default(); N]; M])
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for Matrix<T, M, N> {
+    fn from(rows: [[T; N]; M]) -> Self {
+        Self(rows)
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for [[T; N]; M] {
+    fn from(matrix: Matrix<T, M, N>) -> Self {
+        matrix.0
+    }
+}
+
+/// Index by `(row, column)`.
+impl<T, const M: usize, const N: usize> Index<(usize, usize)> for Matrix<T, M, N> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.0[i][j]
+    }
+}
+
+impl<T, const M: usize, const N: usize> IndexMut<(usize, usize)> for Matrix<T, M, N> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.0[i][j]
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Mul<Vector<T, N>> for Matrix<T, M, N> {
+    type Output = Vector<T, M>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, M> {
+        Vector::new(matrix_vec_multiply(&self.0, rhs.as_array()))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, N, P>> for Matrix<T, M, N> {
+    type Output = Matrix<T, M, P>;
+
+    fn mul(self, rhs: Matrix<T, N, P>) -> Matrix<T, M, P> {
+        Matrix(matrix_multiply(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Add for Matrix<T, M, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(matrix_add(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> AddAssign for Matrix<T, M, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = matrix_add(&self.0, &rhs.0);
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Sub for Matrix<T, M, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(matrix_sub(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> SubAssign for Matrix<T, M, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = matrix_sub(&self.0, &rhs.0);
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self(matrix_scale(&self.0, &rhs))
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> MulAssign<T> for Matrix<T, M, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 = matrix_scale(&self.0, &rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_rows_and_columns() {
+        let matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(matrix.nrows(), 2);
+        assert_eq!(matrix.ncols(), 3);
+        assert_eq!(matrix.row(1), Vector::new([4, 5, 6]));
+        assert_eq!(matrix.column(2), Vector::new([3, 6]));
+        assert_eq!(matrix[(0, 1)], 2);
+        assert_eq!(Matrix::from_columns([[1, 4], [2, 5], [3, 6]]), matrix);
+    }
+
+    #[test]
+    fn test_matrix_vector_multiply() {
+        let matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let vector = Vector::new([1, 0, -1]);
+        assert_eq!(matrix * vector, Vector::new([-2, -2]));
+    }
+
+    #[test]
+    fn test_matrix_matrix_multiply() {
+        let a = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from_rows([[1, 0], [0, 1], [1, -1]]);
+        assert_eq!(a * b, Matrix::from_rows([[4, -1], [10, -1]]));
+    }
+
+    #[test]
+    fn test_matrix_elementwise_operators() {
+        let a = Matrix::from_rows([[1, 2], [3, 4]]);
+        let b = Matrix::from_rows([[4, 3], [2, 1]]);
+        assert_eq!(a + b, Matrix::from_rows([[5, 5], [5, 5]]));
+        assert_eq!(a - b, Matrix::from_rows([[-3, -1], [1, 3]]));
+        assert_eq!(a * 2, Matrix::from_rows([[2, 4], [6, 8]]));
+        assert_eq!(a.transpose(), Matrix::from_rows([[1, 3], [2, 4]]));
+        assert_eq!(a.trace(), 5);
+        assert_eq!(Matrix::identity() * a, a);
+        assert_eq!(a * Matrix::<i32, 2, 2>::zeros(), Matrix::zeros());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_matrix_determinant_and_inverse() {
+        let a = Matrix::from_rows([[2.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(a.determinant(), 1.0);
+        assert_eq!(a.adjugate(), Matrix::from_rows([[1.0, -1.0], [-1.0, 2.0]]));
+        assert_eq!(a.inverse(), Ok(Matrix::from_rows([[1.0, -1.0], [-1.0, 2.0]])));
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{ComplexField, Ring};
+
+/// Matrix Transpose
+///
+/// Swap the rows and columns of a matrix.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::transpose;
+///
+/// let matrix = [[1, 2, 3], [4, 5, 6]];
+/// let expected = [[1, 4], [2, 5], [3, 6]];
+/// assert_eq!(transpose(&matrix), expected);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to transpose.
+///
+/// # Returns
+///
+/// A new `N` by `M` matrix whose `i`-th row is the `i`-th column of the input.
+pub fn transpose<const M: usize, const N: usize, T: Copy>(matrix: &[[T; N]; M]) -> [[T; M]; N] {
+    core::array::from_fn(|i| core::array::from_fn(|j| matrix[j][i]))
+}
+
+/// Conjugate Transpose
+///
+/// Swap the rows and columns of a matrix and conjugate every element. This
+/// is the Hermitian transpose `Aᴴ`, which equals the plain transpose for
+/// real matrices.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{conjugate_transpose, Complex};
+///
+/// let matrix = [[Complex::new(1.0, 2.0), Complex::new(3.0, 0.0)]];
+/// let expected = [[Complex::new(1.0, -2.0)], [Complex::new(3.0, 0.0)]];
+/// assert_eq!(conjugate_transpose(&matrix), expected);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to transpose.
+///
+/// # Returns
+///
+/// A new `N` by `M` matrix whose `i`-th row is the conjugate of the `i`-th
+/// column of the input.
+pub fn conjugate_transpose<const M: usize, const N: usize, T: ComplexField>(matrix: &[[T; N]; M]) -> [[T; M]; N] {
+    core::array::from_fn(|i| core::array::from_fn(|j| matrix[j][i].conj()))
+}
+
+/// Matrix Trace
+///
+/// Sum the elements on the main diagonal of a square matrix.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::trace;
+///
+/// let matrix = [[1, 2], [3, 4]];
+/// assert_eq!(trace(&matrix), 5);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to sum the diagonal of.
+///
+/// # Returns
+///
+/// The sum of the diagonal elements.
+pub fn trace<const N: usize, T: Ring>(matrix: &[[T; N]; N]) -> T {
+    (0..N).fold(T::zero(), |sum, i| sum + matrix[i][i])
+}
+
+/// Identity Matrix
+///
+/// Build the square matrix with ones on the main diagonal and zeros
+/// elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::identity;
+///
+/// assert_eq!(identity::<2, i32>(), [[1, 0], [0, 1]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Returns
+///
+/// The `N` by `N` identity matrix.
+pub fn identity<const N: usize, T: Ring>() -> [[T; N]; N] {
+    from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+}
+
+/// Diagonal Matrix
+///
+/// Build the square matrix with the given elements on the main diagonal and
+/// zeros elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::diagonal;
+///
+/// assert_eq!(diagonal(&[2, 3]), [[2, 0], [0, 3]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `N`: The number of rows and columns in the matrix.
+///
+/// # Arguments
+///
+/// - `vec`: The elements of the diagonal.
+///
+/// # Returns
+///
+/// A new `N` by `N` diagonal matrix.
+pub fn diagonal<const N: usize, T: Ring>(vec: &[T; N]) -> [[T; N]; N] {
+    from_fn(|i, j| if i == j { vec[i] } else { T::zero() })
+}
+
+/// Zero Matrix
+///
+/// Build a matrix whose elements are all zero.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::zeros;
+///
+/// assert_eq!(zeros::<2, 3, i32>(), [[0, 0, 0], [0, 0, 0]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Returns
+///
+/// The `M` by `N` zero matrix.
+pub fn zeros<const M: usize, const N: usize, T: Ring>() -> [[T; N]; M] {
+    [[T::zero(); N]; M]
+}
+
+/// Ones Matrix
+///
+/// Build a matrix whose elements are all one.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::ones;
+///
+/// assert_eq!(ones::<2, 3, i32>(), [[1, 1, 1], [1, 1, 1]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Returns
+///
+/// The `M` by `N` matrix of ones.
+pub fn ones<const M: usize, const N: usize, T: Ring>() -> [[T; N]; M] {
+    [[T::one(); N]; M]
+}
+
+/// Matrix From Function
+///
+/// Build a matrix by calling a function with the row and column of each
+/// element.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::from_fn;
+///
+/// let matrix: [[usize; 3]; 2] = from_fn(|i, j| 10 * i + j);
+/// assert_eq!(matrix, [[0, 1, 2], [10, 11, 12]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `f`: The function returning the element at a row and column.
+///
+/// # Returns
+///
+/// A new `M` by `N` matrix.
+pub fn from_fn<const M: usize, const N: usize, T, F: FnMut(usize, usize) -> T>(mut f: F) -> [[T; N]; M] {
+    core::array::from_fn(|i| core::array::from_fn(|j| f(i, j)))
+}
+
+/// Matrix Addition
+///
+/// Add two matrices together element by element.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::matrix_add;
+///
+/// let a = [[1, 2], [3, 4]];
+/// let b = [[4, 3], [2, 1]];
+/// assert_eq!(matrix_add(&a, &b), [[5, 5], [5, 5]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrices.
+/// - `N`: The number of columns in the matrices.
+///
+/// # Arguments
+///
+/// - `matrix_a`: The first matrix.
+/// - `matrix_b`: The second matrix.
+///
+/// # Returns
+///
+/// A new matrix containing the sum of the two input matrices.
+pub fn matrix_add<const M: usize, const N: usize, T: Ring>(matrix_a: &[[T; N]; M], matrix_b: &[[T; N]; M]) -> [[T; N]; M] {
+    from_fn(|i, j| matrix_a[i][j] + matrix_b[i][j])
+}
+
+/// Matrix Subtraction
+///
+/// Subtract two matrices element by element.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::matrix_sub;
+///
+/// let a = [[1, 2], [3, 4]];
+/// let b = [[4, 3], [2, 1]];
+/// assert_eq!(matrix_sub(&a, &b), [[-3, -1], [1, 3]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrices.
+/// - `N`: The number of columns in the matrices.
+///
+/// # Arguments
+///
+/// - `matrix_a`: The first matrix.
+/// - `matrix_b`: The second matrix.
+///
+/// # Returns
+///
+/// A new matrix containing the difference of the two input matrices.
+pub fn matrix_sub<const M: usize, const N: usize, T: Ring>(matrix_a: &[[T; N]; M], matrix_b: &[[T; N]; M]) -> [[T; N]; M] {
+    from_fn(|i, j| matrix_a[i][j] - matrix_b[i][j])
+}
+
+/// Matrix Scaling
+///
+/// Scale every element of the matrix a specified amount.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::matrix_scale;
+///
+/// let a = [[1, 2], [3, 4]];
+/// assert_eq!(matrix_scale(&a, &2), [[2, 4], [6, 8]]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to scale.
+/// - `scalar`: The scalar value to multiply the matrix by.
+///
+/// # Returns
+///
+/// A new matrix containing the scaled values of the input matrix.
+pub fn matrix_scale<const M: usize, const N: usize, T: Ring>(matrix: &[[T; N]; M], scalar: &T) -> [[T; N]; M] {
+    from_fn(|i, j| matrix[i][j] * *scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix_multiply;
+
+    #[test]
+    fn test_transpose() {
+        let matrix = [[1, 2, 3], [4, 5, 6]];
+        assert_eq!(transpose(&matrix), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(transpose(&transpose(&matrix)), matrix);
+    }
+
+    #[test]
+    fn test_trace() {
+        assert_eq!(trace(&[[1, 2, 3], [4, 5, 6], [7, 8, 9]]), 15);
+        assert_eq!(trace(&identity::<4, f64>()), 4.0);
+    }
+
+    #[test]
+    fn test_constructors() {
+        let matrix = [[1, 2], [3, 4]];
+        assert_eq!(matrix_multiply(&identity(), &matrix), matrix);
+        assert_eq!(diagonal(&[2, 3, 4]), [[2, 0, 0], [0, 3, 0], [0, 0, 4]]);
+        assert_eq!(zeros::<1, 2, u8>(), [[0, 0]]);
+        assert_eq!(ones::<2, 1, f32>(), [[1.0], [1.0]]);
+        assert_eq!(from_fn::<2, 2, _, _>(|i, j| i * j), [[0, 0], [0, 1]]);
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let a = [[1, 2], [3, 4]];
+        let b = [[4, 3], [2, 1]];
+        assert_eq!(matrix_add(&a, &b), [[5, 5], [5, 5]]);
+        assert_eq!(matrix_sub(&a, &b), [[-3, -1], [1, 3]]);
+        assert_eq!(matrix_scale(&a, &-1), [[-1, -2], [-3, -4]]);
+    }
+}
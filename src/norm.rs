@@ -0,0 +1,445 @@
+use crate::{Abs, ComplexField, RealField, Ring, VectorError, Zero};
+
+/// Dot Product
+///
+/// Multiply two vectors element by element and sum the products.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::dot;
+///
+/// let a = [1, 2, 3];
+/// let b = [4, -5, 6];
+/// assert_eq!(dot(&a, &b), 12);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The sum of the products of the corresponding elements.
+pub fn dot<const F: usize, T: Ring>(vec_a: &[T; F], vec_b: &[T; F]) -> T {
+    vec_a
+        .iter()
+        .zip(vec_b)
+        .fold(T::zero(), |sum, (a, b)| sum + *a * *b)
+}
+
+/// Inner Product
+///
+/// The dot product with the first vector conjugated, `Σ conj(aᵢ) bᵢ`, so
+/// that the inner product of a complex vector with itself is its real,
+/// nonnegative squared length. For real vectors it is the same as [`dot`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{dot, inner_product, Complex};
+///
+/// let a = [Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+/// assert_eq!(inner_product(&a, &a), Complex::new(5.0, 0.0));
+/// assert_eq!(dot(&a, &a), Complex::new(3.0, 0.0));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The vector to conjugate.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The sum of the products of the conjugated elements of `vec_a` with the
+/// corresponding elements of `vec_b`.
+pub fn inner_product<const F: usize, T: ComplexField>(vec_a: &[T; F], vec_b: &[T; F]) -> T {
+    vec_a
+        .iter()
+        .zip(vec_b)
+        .fold(T::zero(), |sum, (a, b)| sum + a.conj() * *b)
+}
+
+/// Squared Length
+///
+/// The dot product of a vector with itself, which is the square of its
+/// Euclidean length without taking a root.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::squared_length;
+///
+/// assert_eq!(squared_length(&[3, 4]), 25);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to measure.
+///
+/// # Returns
+///
+/// The sum of the squares of the elements.
+pub fn squared_length<const F: usize, T: Ring>(vec: &[T; F]) -> T {
+    dot(vec, vec)
+}
+
+/// Manhattan Norm
+///
+/// The sum of the absolute values of the elements.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::norm_l1;
+///
+/// assert_eq!(norm_l1(&[3, -4, 1]), 8);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to measure.
+///
+/// # Returns
+///
+/// The L1 norm of the vector.
+pub fn norm_l1<const F: usize, T: Ring + Abs>(vec: &[T; F]) -> T {
+    vec.iter().fold(T::zero(), |sum, a| sum + a.abs())
+}
+
+/// Euclidean Norm
+///
+/// The square root of the sum of the squared moduli of the elements, which
+/// is real for complex vectors too. The elements are scaled by the largest
+/// modulus before squaring, so the norm neither overflows nor underflows
+/// unless the result itself does.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{norm_l2, Complex};
+///
+/// assert_eq!(norm_l2(&[3.0, -4.0]), 5.0);
+/// assert!((norm_l2(&[3e200_f64, -4e200]) / 5e200 - 1.0).abs() < 1e-15);
+/// assert_eq!(norm_l2(&[Complex::new(3.0, 4.0), Complex::new(0.0, 0.0)]), 5.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to measure.
+///
+/// # Returns
+///
+/// The L2 norm of the vector.
+pub fn norm_l2<const F: usize, T: ComplexField>(vec: &[T; F]) -> T::Real {
+    scaled_norm(vec.iter().copied())
+}
+
+/// Maximum Norm
+///
+/// The largest absolute value of the elements. The norm of an empty vector
+/// is zero.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::norm_inf;
+///
+/// assert_eq!(norm_inf(&[3, -4, 1]), 4);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to measure.
+///
+/// # Returns
+///
+/// The L-infinity norm of the vector.
+pub fn norm_inf<const F: usize, T: Ring + Abs + PartialOrd>(vec: &[T; F]) -> T {
+    vec.iter().fold(T::zero(), |max, a| {
+        let a = a.abs();
+        if a > max {
+            a
+        } else {
+            max
+        }
+    })
+}
+
+/// P-Norm
+///
+/// The `p`-th root of the sum of the absolute values of the elements raised
+/// to the power `p`, scaled like [`norm_l2`] so that it does not overflow.
+/// An infinite `p` gives the maximum norm, the limit as `p` grows. `p` below
+/// one gives a quasi-norm that breaks the triangle inequality, and `p` that
+/// is zero, negative or NaN gives NaN.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::norm_p;
+///
+/// let norm = norm_p(&[3.0_f64, -4.0], 3.0);
+/// assert!((norm - 91.0_f64.cbrt()).abs() < 1e-12);
+/// assert_eq!(norm_p(&[3.0, -4.0], f64::INFINITY), 4.0);
+/// assert!(norm_p(&[3.0_f64, -4.0], 0.0).is_nan());
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to measure.
+/// - `p`: The order of the norm, which must be positive.
+///
+/// # Returns
+///
+/// The Lp norm of the vector.
+pub fn norm_p<const F: usize, T: RealField>(vec: &[T; F], p: T) -> T {
+    let zero = T::zero();
+    if p.partial_cmp(&zero) != Some(core::cmp::Ordering::Greater) {
+        return T::from_f64(f64::NAN);
+    }
+    if !p.is_finite() {
+        return norm_inf(vec);
+    }
+    let largest = norm_inf(vec);
+    if largest.is_zero() || !largest.is_finite() {
+        return vec.iter().fold(largest, |sum, a| sum + a.abs());
+    }
+    let sum = vec.iter().fold(zero, |sum, a| sum + (a.abs() / largest).powf(p));
+    largest * sum.powf(p.recip())
+}
+
+/// Euclidean Distance
+///
+/// The Euclidean norm of the difference of two vectors.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::distance;
+///
+/// assert_eq!(distance(&[1.0, 1.0], &[4.0, 5.0]), 5.0);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The distance between the two points.
+pub fn distance<const F: usize, T: RealField>(vec_a: &[T; F], vec_b: &[T; F]) -> T {
+    let difference: [T; F] = core::array::from_fn(|i| vec_a[i] - vec_b[i]);
+    norm_l2(&difference)
+}
+
+/// Vector Normalization
+///
+/// Scale a vector to unit Euclidean length. The zero vector has no
+/// direction, so normalizing it produces NaN elements; use
+/// [`try_normalize`] to detect it instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::normalize;
+///
+/// assert_eq!(normalize(&[3.0, 4.0]), [0.6, 0.8]);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to normalize.
+///
+/// # Returns
+///
+/// A new vector with the direction of the input and a length of one.
+pub fn normalize<const F: usize, T: RealField>(vec: &[T; F]) -> [T; F] {
+    let length = norm_l2(vec);
+    core::array::from_fn(|i| vec[i] / length)
+}
+
+/// Fallible Vector Normalization
+///
+/// Scale a vector to unit Euclidean length, failing on the zero vector. Any
+/// other vector can be normalized, however small its elements.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{try_normalize, VectorError};
+///
+/// assert_eq!(try_normalize(&[3.0, 4.0]), Ok([0.6, 0.8]));
+/// assert_eq!(try_normalize(&[0.0, 0.0]), Err(VectorError::ZeroVector));
+/// assert_eq!(try_normalize(&[1e-200, 0.0]), Ok([1.0, 0.0]));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to normalize.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if every element of the vector is
+/// zero.
+///
+/// # Returns
+///
+/// A new vector with the direction of the input and a length of one.
+pub fn try_normalize<const F: usize, T: RealField>(vec: &[T; F]) -> Result<[T; F], VectorError> {
+    let length = norm_l2(vec);
+    if length.is_zero() {
+        return Err(VectorError::ZeroVector);
+    }
+    Ok(core::array::from_fn(|i| vec[i] / length))
+}
+
+/// The Euclidean length of `values`, scaled by the largest modulus when
+/// squaring would overflow or underflow. Takes an iterator so that strided
+/// data such as matrix columns can be measured in place.
+pub(crate) fn scaled_norm<T: ComplexField>(values: impl Iterator<Item = T> + Clone) -> T::Real {
+    // The plain sum is the most accurate whenever any square that rounded
+    // to a subnormal is too small to matter.
+    let sum = values.clone().fold(T::Real::zero(), |sum, a| sum + a.norm_sqr());
+    if sum.is_finite() && sum >= T::Real::min_positive() / T::Real::epsilon() {
+        return sum.sqrt();
+    }
+    let largest = values.clone().fold(T::Real::zero(), |max, a| max.max(a.modulus()));
+    if largest.is_zero() || !largest.is_finite() {
+        // Zero or infinite, unless an element is NaN, which the sum keeps.
+        return values.fold(largest, |sum, a| sum + a.modulus());
+    }
+    let sum = values.fold(T::Real::zero(), |sum, a| {
+        let scaled = a.modulus() / largest;
+        sum + scaled * scaled
+    });
+    largest * sum.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(dot(&[1, 2, 3], &[4, -5, 6]), 12);
+        assert_eq!(dot(&[1.5, 2.0], &[2.0, 0.25]), 3.5);
+        assert_eq!(squared_length(&[3, 4]), 25);
+    }
+
+    #[test]
+    fn test_integer_norms() {
+        assert_eq!(norm_l1(&[3, -4, 1]), 8);
+        assert_eq!(norm_l1(&[3u8, 4, 1]), 8);
+        assert_eq!(norm_inf(&[3, -4, 1]), 4);
+        assert_eq!(norm_inf::<0, i32>(&[]), 0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_float_norms() {
+        let a = [3.0_f64, -4.0];
+        assert_eq!(norm_l1(&a), 7.0);
+        assert_eq!(norm_l2(&a), 5.0);
+        assert_eq!(norm_inf(&a), 4.0);
+        assert!((norm_p(&a, 1.0) - 7.0).abs() < 1e-12);
+        assert!((norm_p(&a, 2.0) - 5.0).abs() < 1e-12);
+        assert_eq!(distance(&[1.0_f32, 1.0], &[4.0, 5.0]), 5.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_norm_extremes() {
+        assert_eq!(norm_l2(&[3e-200, 4e-200]), 5e-200);
+        assert_eq!(norm_l2(&[1e200, 1e200]), 2.0_f64.sqrt() * 1e200);
+        assert_eq!(norm_l2(&[3e30_f32, 4e30]), 5e30);
+        assert_eq!(norm_l2(&[0.0, 0.0]), 0.0);
+        assert_eq!(norm_l2(&[f64::INFINITY, 1.0]), f64::INFINITY);
+        assert!(norm_l2(&[f64::NAN, 0.0]).is_nan());
+        assert!(norm_l2(&[f64::NAN, 1.0]).is_nan());
+        assert!((norm_p(&[3e200, -4e200], 2.0) / 5e200 - 1.0).abs() < 1e-12);
+        assert_eq!(norm_p(&[1.0, -7.0, 2.0], f64::INFINITY), 7.0);
+        assert!(norm_p(&[1.0_f64, 2.0], -1.0).is_nan());
+        assert!(norm_p(&[1.0, 2.0], f64::NAN).is_nan());
+        assert!((norm_p(&[1.0, 1.0], 0.5) - 4.0).abs() < 1e-12);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_complex_norm_l2() {
+        use crate::Complex;
+        assert!((norm_l2(&[Complex::new(1.0, 2.0), Complex::new(-2.0, 4.0)]) - 5.0).abs() < 1e-15);
+        assert!((norm_l2(&[Complex::new(3e200, 0.0), Complex::new(0.0, -4e200)]) / 5e200 - 1.0).abs() < 1e-15);
+        assert!((norm_l2(&[Complex::new(3e-200, -4e-200)]) / 5e-200 - 1.0).abs() < 1e-15);
+        assert_eq!(norm_l2(&[Complex::<f64>::zero(); 2]), 0.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(&[3.0, 4.0]), [0.6, 0.8]);
+        assert!(normalize(&[0.0_f64, 0.0])[0].is_nan());
+        assert_eq!(try_normalize(&[0.0, 0.0, 2.0]), Ok([0.0, 0.0, 1.0]));
+        assert_eq!(try_normalize(&[0.0_f32, 0.0]), Err(VectorError::ZeroVector));
+        assert_eq!(try_normalize(&[1e-200, 0.0]), Ok([1.0, 0.0]));
+        // Subnormal elements, whose squares are zero.
+        let tiny = try_normalize(&[3e-320_f64, -4e-320]).unwrap();
+        assert!((tiny[0] - 0.6).abs() < 1e-3 && (tiny[1] + 0.8).abs() < 1e-3);
+        let huge = normalize(&[3e200_f64, 4e200]);
+        assert!((huge[0] - 0.6).abs() < 1e-15 && (huge[1] - 0.8).abs() < 1e-15);
+    }
+}
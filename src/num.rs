@@ -0,0 +1,607 @@
+use core::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Additive Identity
+///
+/// Types with a zero element.
+pub trait Zero: Sized {
+    /// The value `0`.
+    fn zero() -> Self;
+
+    /// Whether `self` is equal to zero.
+    fn is_zero(&self) -> bool;
+}
+
+/// Multiplicative Identity
+///
+/// Types with a unit element.
+pub trait One: Sized {
+    /// The value `1`.
+    fn one() -> Self;
+}
+
+/// Scalar
+///
+/// The minimum requirements on the element type of a vector or matrix.
+/// Implemented automatically for every type that meets them.
+pub trait Scalar: Copy + PartialEq + Debug {}
+
+impl<T: Copy + PartialEq + Debug> Scalar for T {}
+
+/// Ring
+///
+/// Scalars that can be added, subtracted and multiplied, which is all the
+/// element-wise operations, products and integer norms need. Implemented
+/// automatically for every type with [`Zero`], [`One`] and the arithmetic
+/// operators, including all primitive integers and floats.
+pub trait Ring:
+    Scalar
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+}
+
+impl<T> Ring for T where
+    T: Scalar
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + MulAssign
+{
+}
+
+/// Field
+///
+/// Rings where every non-zero element has a multiplicative inverse, so
+/// division is exact. Integers have a `Div` operator but are not fields, so
+/// this trait must be implemented explicitly.
+pub trait Field: Ring + Neg<Output = Self> + Div<Output = Self> + DivAssign {
+    /// The reciprocal `1 / self`.
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+}
+
+/// Elimination
+///
+/// Scalars whose determinant can be found by elimination, which is how
+/// [`determinant`](crate::determinant) handles matrices larger than 4 by 4.
+/// The default is fraction-free Bareiss elimination, whose divisions are
+/// exact, so it suits the primitive integers and other exact rings. Floats
+/// and [`Complex`](crate::Complex) numbers use LU decomposition with partial
+/// pivoting instead, because Bareiss only avoids pivots that are exactly zero
+/// and loses all accuracy on tiny ones.
+pub trait Elimination: Ring + Div<Output = Self> {
+    /// The determinant of the `n` by `n` row-major matrix in `a`, which is
+    /// overwritten.
+    fn eliminate(a: &mut [Self], n: usize) -> Self {
+        crate::determinant::bareiss(a, n)
+    }
+}
+
+/// Absolute Value
+///
+/// Types with an absolute value, used by the norms that are meaningful for
+/// integers. Unsigned integers are their own absolute value.
+pub trait Abs {
+    /// The absolute value of `self`.
+    fn abs(self) -> Self;
+}
+
+/// Integer
+///
+/// Primitive integers, whose arithmetic can overflow. Each operation comes in
+/// the four overflow policies of the standard library so that vector code
+/// can choose its semantics explicitly.
+pub trait Integer: Ring + Ord {
+    /// Addition that returns `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtraction that returns `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplication that returns `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Addition that wraps around at the boundary of the type.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Subtraction that wraps around at the boundary of the type.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Multiplication that wraps around at the boundary of the type.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Addition that clamps to the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtraction that clamps to the bounds of the type.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Multiplication that clamps to the bounds of the type.
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// Wrapping addition along with whether it overflowed.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    /// Wrapping subtraction along with whether it overflowed.
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+
+    /// Wrapping multiplication along with whether it overflowed.
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+
+    /// The sum of the products of corresponding elements of `a` and `b`,
+    /// computed exactly and clamped to the bounds of the type once at the
+    /// end, so the result does not depend on the order of the terms.
+    fn saturating_dot(a: &[Self], b: &[Self]) -> Self;
+}
+
+/// Complex Field
+///
+/// Fields with a complex conjugate and a real modulus, which is what the
+/// conjugated dot product, the Euclidean norm and the LU and Cholesky
+/// decompositions need. Every [`RealField`] is its own conjugate, and
+/// [`Complex`](crate::Complex) numbers over a real field implement it as
+/// well. The QR, singular value and eigenvalue decompositions still need a
+/// [`RealField`].
+pub trait ComplexField: Field {
+    /// The type of the real and imaginary parts.
+    type Real: RealField;
+
+    /// The complex conjugate of `self`.
+    fn conj(self) -> Self;
+
+    /// The real part of `self`.
+    fn real(self) -> Self::Real;
+
+    /// The modulus `|self|`.
+    fn modulus(self) -> Self::Real;
+
+    /// The square of the modulus, without taking a root.
+    fn norm_sqr(self) -> Self::Real;
+
+    /// Convert a real number to this type.
+    fn from_real(value: Self::Real) -> Self;
+}
+
+/// Real Field
+///
+/// Ordered fields with the roots, powers and trigonometric functions needed
+/// by the Euclidean norms, decompositions and rotations. Implemented for
+/// `f32` and `f64` when either the `std` or `libm` feature is enabled.
+pub trait RealField: ComplexField<Real = Self> + PartialOrd + Abs {
+    /// The square root of `self`.
+    fn sqrt(self) -> Self;
+
+    /// `self` raised to the power `n`.
+    fn powf(self, n: Self) -> Self;
+
+    /// The natural exponential of `self`.
+    fn exp(self) -> Self;
+
+    /// The natural logarithm of `self`.
+    fn ln(self) -> Self;
+
+    /// The sine of `self` in radians.
+    fn sin(self) -> Self;
+
+    /// The cosine of `self` in radians.
+    fn cos(self) -> Self;
+
+    /// The tangent of `self` in radians.
+    fn tan(self) -> Self;
+
+    /// The arcsine of `self` in radians.
+    fn asin(self) -> Self;
+
+    /// The arccosine of `self` in radians.
+    fn acos(self) -> Self;
+
+    /// The four quadrant arctangent of `self` and `other` in radians.
+    fn atan2(self, other: Self) -> Self;
+
+    /// The difference between `1` and the next representable value.
+    fn epsilon() -> Self;
+
+    /// The smallest positive normal value.
+    fn min_positive() -> Self;
+
+    /// Archimedes' constant.
+    fn pi() -> Self;
+
+    /// Convert a constant to this type, rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+
+    /// Whether `self` is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    /// The larger of `self` and `other`.
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The smaller of `self` and `other`.
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Zero for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                1
+            }
+        }
+
+        impl Elimination for $t {}
+
+        impl Integer for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, rhs)
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_sub(self, rhs)
+            }
+
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_mul(self, rhs)
+            }
+
+            fn saturating_dot(a: &[Self], b: &[Self]) -> Self {
+                let zero = <$t as Zero>::zero();
+                let (negative, magnitude) = crate::overflow::exact_dot(a, b, |x: $t| (x < zero, x.abs_diff(zero) as u128));
+                if !negative {
+                    if magnitude > <$t>::MAX.abs_diff(zero) as u128 {
+                        <$t>::MAX
+                    } else {
+                        magnitude as $t
+                    }
+                } else if magnitude > <$t>::MIN.abs_diff(zero) as u128 {
+                    <$t>::MIN
+                } else {
+                    (magnitude as $t).wrapping_neg()
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_abs_signed {
+    ($($t:ty),*) => {$(
+        impl Abs for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_abs_unsigned {
+    ($($t:ty),*) => {$(
+        impl Abs for $t {
+            fn abs(self) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_abs_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+impl_abs_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {$(
+        impl Zero for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0.0
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                1.0
+            }
+        }
+
+        impl Field for $t {}
+
+        impl Elimination for $t {
+            fn eliminate(a: &mut [Self], n: usize) -> Self {
+                crate::lu::lu_determinant(a, n, <$t as Abs>::abs)
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl ComplexField for $t {
+            type Real = $t;
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn real(self) -> Self {
+                self
+            }
+
+            fn modulus(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn norm_sqr(self) -> Self {
+                self * self
+            }
+
+            fn from_real(value: Self) -> Self {
+                value
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl RealField for $t {
+            fn sqrt(self) -> Self {
+                math::$t::sqrt(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                math::$t::powf(self, n)
+            }
+
+            fn exp(self) -> Self {
+                math::$t::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                math::$t::ln(self)
+            }
+
+            fn sin(self) -> Self {
+                math::$t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                math::$t::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                math::$t::tan(self)
+            }
+
+            fn asin(self) -> Self {
+                math::$t::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                math::$t::acos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                math::$t::atan2(self, other)
+            }
+
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn min_positive() -> Self {
+                $t::MIN_POSITIVE
+            }
+
+            fn pi() -> Self {
+                core::$t::consts::PI
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+/// The floating point functions that live in `std`, or in `libm` without it.
+#[cfg(feature = "std")]
+mod math {
+    macro_rules! forward {
+        ($t:ident: $($name:ident($($arg:ident),*)),*) => {
+            pub mod $t {
+                $(
+                    pub fn $name($($arg: $t),*) -> $t {
+                        $t::$name($($arg),*)
+                    }
+                )*
+            }
+        };
+    }
+
+    forward!(f32: sqrt(x), powf(x, n), exp(x), ln(x), sin(x), cos(x), tan(x), asin(x), acos(x), atan2(y, x));
+    forward!(f64: sqrt(x), powf(x, n), exp(x), ln(x), sin(x), cos(x), tan(x), asin(x), acos(x), atan2(y, x));
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod math {
+    pub mod f32 {
+        pub use libm::{acosf as acos, asinf as asin, atan2f as atan2, cosf as cos, expf as exp, logf as ln, powf, sinf as sin, sqrtf as sqrt, tanf as tan};
+    }
+
+    pub mod f64 {
+        pub use libm::{acos, asin, atan2, cos, exp, log as ln, pow as powf, sin, sqrt, tan};
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dot, matrix_vec_multiply};
+
+    /// Integers modulo 7, which only need `Zero`, `One` and the arithmetic
+    /// operators to be a `Ring`.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Mod7(u8);
+
+    impl Zero for Mod7 {
+        fn zero() -> Self {
+            Mod7(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl One for Mod7 {
+        fn one() -> Self {
+            Mod7(1)
+        }
+    }
+
+    impl Add for Mod7 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Mod7((self.0 + rhs.0) % 7)
+        }
+    }
+
+    impl Sub for Mod7 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Mod7((self.0 + 7 - rhs.0) % 7)
+        }
+    }
+
+    impl Mul for Mod7 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Mod7((self.0 * rhs.0) % 7)
+        }
+    }
+
+    impl AddAssign for Mod7 {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl SubAssign for Mod7 {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl MulAssign for Mod7 {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+
+    fn sum_of_squares<T: Ring>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |sum, a| sum + *a * *a)
+    }
+
+    #[test]
+    fn test_primitive_rings() {
+        assert_eq!(sum_of_squares(&[1u8, 2, 3]), 14);
+        assert_eq!(sum_of_squares(&[-1i64, 2]), 5);
+        assert_eq!(sum_of_squares(&[0.5f32, 1.5]), 2.5);
+        assert!(i32::zero().is_zero());
+        assert_eq!(f64::one().recip(), 1.0);
+    }
+
+    #[test]
+    fn test_custom_ring() {
+        let a = [Mod7(3), Mod7(4)];
+        let b = [Mod7(5), Mod7(6)];
+        assert_eq!(dot(&a, &b), Mod7(4));
+        assert_eq!(matrix_vec_multiply(&[a, b], &[Mod7(1), Mod7(1)]), [Mod7(0), Mod7(4)]);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_real_field() {
+        assert_eq!(RealField::sqrt(9.0f64), 3.0);
+        assert_eq!(RealField::max(1.0f32, 2.0), 2.0);
+        assert_eq!(RealField::min(1.0f32, 2.0), 1.0);
+        assert!((RealField::atan2(1.0f64, 1.0) - f64::pi() / 4.0).abs() < 1e-15);
+        assert!(!RealField::is_finite(f64::NAN));
+    }
+}
@@ -0,0 +1,668 @@
+use crate::Integer;
+
+/// Checked Vector Addition
+///
+/// Add two vectors together, returning `None` if any operation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::checked_add;
+///
+/// let a = [250u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(checked_add(&a, &b), None);
+/// assert_eq!(checked_add(&[1u8, 2], &[3, 4]), Some([4, 6]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result, or `None` if any operation overflowed.
+pub fn checked_add<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> Option<[T; F]> {
+    let mut result = [T::zero(); F];
+    for (r, (a, b)) in result.iter_mut().zip(vec_a.iter().zip(vec_b)) {
+        *r = a.checked_add(*b)?;
+    }
+    Some(result)
+}
+
+/// Wrapping Vector Addition
+///
+/// Add two vectors together, wrapping around at the boundary of the type on
+/// overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::wrapping_add;
+///
+/// let a = [250u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(wrapping_add(&a, &b), [4, 2]);
+/// assert_eq!(wrapping_add(&[1u8, 2], &[3, 4]), [4, 6]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result with every operation wrapped.
+pub fn wrapping_add<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| vec_a[i].wrapping_add(vec_b[i]))
+}
+
+/// Saturating Vector Addition
+///
+/// Add two vectors together, clamping each operation to the bounds of the type
+/// on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::saturating_add;
+///
+/// let a = [250u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(saturating_add(&a, &b), [255, 2]);
+/// assert_eq!(saturating_add(&[1u8, 2], &[3, 4]), [4, 6]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result with every operation saturated.
+pub fn saturating_add<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| vec_a[i].saturating_add(vec_b[i]))
+}
+
+/// Overflowing Vector Addition
+///
+/// Add two vectors together, wrapping on overflow and reporting whether any
+/// operation overflowed.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::overflowing_add;
+///
+/// let a = [250u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(overflowing_add(&a, &b), ([4, 2], true));
+/// assert_eq!(overflowing_add(&[1u8, 2], &[3, 4]), ([4, 6], false));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The wrapped result and whether any operation overflowed.
+pub fn overflowing_add<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> ([T; F], bool) {
+    let mut overflowed = false;
+    let result = core::array::from_fn(|i| {
+        let (value, overflow) = vec_a[i].overflowing_add(vec_b[i]);
+        overflowed |= overflow;
+        value
+    });
+    (result, overflowed)
+}
+
+/// Checked Vector Subtraction
+///
+/// Subtract two vectors, returning `None` if any operation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::checked_sub;
+///
+/// let a = [5u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(checked_sub(&a, &b), None);
+/// assert_eq!(checked_sub(&[5u8, 4], &[3, 4]), Some([2, 0]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result, or `None` if any operation overflowed.
+pub fn checked_sub<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> Option<[T; F]> {
+    let mut result = [T::zero(); F];
+    for (r, (a, b)) in result.iter_mut().zip(vec_a.iter().zip(vec_b)) {
+        *r = a.checked_sub(*b)?;
+    }
+    Some(result)
+}
+
+/// Wrapping Vector Subtraction
+///
+/// Subtract two vectors, wrapping around at the boundary of the type on
+/// overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::wrapping_sub;
+///
+/// let a = [5u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(wrapping_sub(&a, &b), [251, 0]);
+/// assert_eq!(wrapping_sub(&[5u8, 4], &[3, 4]), [2, 0]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result with every operation wrapped.
+pub fn wrapping_sub<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| vec_a[i].wrapping_sub(vec_b[i]))
+}
+
+/// Saturating Vector Subtraction
+///
+/// Subtract two vectors, clamping each operation to the bounds of the type on
+/// overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::saturating_sub;
+///
+/// let a = [5u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(saturating_sub(&a, &b), [0, 0]);
+/// assert_eq!(saturating_sub(&[5u8, 4], &[3, 4]), [2, 0]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The result with every operation saturated.
+pub fn saturating_sub<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> [T; F] {
+    core::array::from_fn(|i| vec_a[i].saturating_sub(vec_b[i]))
+}
+
+/// Overflowing Vector Subtraction
+///
+/// Subtract two vectors, wrapping on overflow and reporting whether any
+/// operation overflowed.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::overflowing_sub;
+///
+/// let a = [5u8, 1];
+/// let b = [10, 1];
+/// assert_eq!(overflowing_sub(&a, &b), ([251, 0], true));
+/// assert_eq!(overflowing_sub(&[5u8, 4], &[3, 4]), ([2, 0], false));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vectors.
+///
+/// # Arguments
+///
+/// - `vec_a`: The first vector.
+/// - `vec_b`: The second vector.
+///
+/// # Returns
+///
+/// The wrapped result and whether any operation overflowed.
+pub fn overflowing_sub<const F: usize, T: Integer>(vec_a: &[T; F], vec_b: &[T; F]) -> ([T; F], bool) {
+    let mut overflowed = false;
+    let result = core::array::from_fn(|i| {
+        let (value, overflow) = vec_a[i].overflowing_sub(vec_b[i]);
+        overflowed |= overflow;
+        value
+    });
+    (result, overflowed)
+}
+
+/// Checked Vector Scaling
+///
+/// Scale the vector a specified amount, returning `None` if any operation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::checked_scale;
+///
+/// let a = [100u8, 2];
+/// assert_eq!(checked_scale(&a, &3), None);
+/// assert_eq!(checked_scale(&[10u8, 2], &3), Some([30, 6]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to scale.
+/// - `scalar`: The scalar value to multiply the vector by.
+///
+/// # Returns
+///
+/// The result, or `None` if any operation overflowed.
+pub fn checked_scale<const F: usize, T: Integer>(vec: &[T; F], scalar: &T) -> Option<[T; F]> {
+    let mut result = [T::zero(); F];
+    for (r, a) in result.iter_mut().zip(vec) {
+        *r = a.checked_mul(*scalar)?;
+    }
+    Some(result)
+}
+
+/// Wrapping Vector Scaling
+///
+/// Scale the vector a specified amount, wrapping around at the boundary of the
+/// type on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::wrapping_scale;
+///
+/// let a = [100u8, 2];
+/// assert_eq!(wrapping_scale(&a, &3), [44, 6]);
+/// assert_eq!(wrapping_scale(&[10u8, 2], &3), [30, 6]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to scale.
+/// - `scalar`: The scalar value to multiply the vector by.
+///
+/// # Returns
+///
+/// The result with every operation wrapped.
+pub fn wrapping_scale<const F: usize, T: Integer>(vec: &[T; F], scalar: &T) -> [T; F] {
+    core::array::from_fn(|i| vec[i].wrapping_mul(*scalar))
+}
+
+/// Saturating Vector Scaling
+///
+/// Scale the vector a specified amount, clamping each operation to the bounds
+/// of the type on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::saturating_scale;
+///
+/// let a = [100u8, 2];
+/// assert_eq!(saturating_scale(&a, &3), [255, 6]);
+/// assert_eq!(saturating_scale(&[10u8, 2], &3), [30, 6]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to scale.
+/// - `scalar`: The scalar value to multiply the vector by.
+///
+/// # Returns
+///
+/// The result with every operation saturated.
+pub fn saturating_scale<const F: usize, T: Integer>(vec: &[T; F], scalar: &T) -> [T; F] {
+    core::array::from_fn(|i| vec[i].saturating_mul(*scalar))
+}
+
+/// Overflowing Vector Scaling
+///
+/// Scale the vector a specified amount, wrapping on overflow and reporting
+/// whether any operation overflowed.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::overflowing_scale;
+///
+/// let a = [100u8, 2];
+/// assert_eq!(overflowing_scale(&a, &3), ([44, 6], true));
+/// assert_eq!(overflowing_scale(&[10u8, 2], &3), ([30, 6], false));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `F`: The length of the vector.
+///
+/// # Arguments
+///
+/// - `vec`: The vector to scale.
+/// - `scalar`: The scalar value to multiply the vector by.
+///
+/// # Returns
+///
+/// The wrapped result and whether any operation overflowed.
+pub fn overflowing_scale<const F: usize, T: Integer>(vec: &[T; F], scalar: &T) -> ([T; F], bool) {
+    let mut overflowed = false;
+    let result = core::array::from_fn(|i| {
+        let (value, overflow) = vec[i].overflowing_mul(*scalar);
+        overflowed |= overflow;
+        value
+    });
+    (result, overflowed)
+}
+
+/// Checked Matrix Vector Multiplication
+///
+/// Multiply the vector by the matrix, returning `None` if any operation
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::checked_matrix_vec_multiply;
+///
+/// let matrix = [[100u8, 100], [1, 2]];
+/// let vector = [2, 1];
+/// assert_eq!(checked_matrix_vec_multiply(&matrix, &vector), None);
+/// assert_eq!(checked_matrix_vec_multiply(&[[1u8, 2], [3, 4]], &[1, 1]), Some([3, 7]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Returns
+///
+/// The result, or `None` if any operation overflowed.
+pub fn checked_matrix_vec_multiply<const M: usize, const N: usize, T: Integer>(matrix: &[[T; N]; M], vector: &[T; N]) -> Option<[T; M]> {
+    let mut result = [T::zero(); M];
+    for (r, row) in result.iter_mut().zip(matrix) {
+        for (a, b) in row.iter().zip(vector) {
+            *r = r.checked_add(a.checked_mul(*b)?)?;
+        }
+    }
+    Some(result)
+}
+
+/// Wrapping Matrix Vector Multiplication
+///
+/// Multiply the vector by the matrix, wrapping around at the boundary of the
+/// type on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::wrapping_matrix_vec_multiply;
+///
+/// let matrix = [[100u8, 100], [1, 2]];
+/// let vector = [2, 1];
+/// assert_eq!(wrapping_matrix_vec_multiply(&matrix, &vector), [44, 4]);
+/// assert_eq!(wrapping_matrix_vec_multiply(&[[1u8, 2], [3, 4]], &[1, 1]), [3, 7]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Returns
+///
+/// The result with every operation wrapped.
+pub fn wrapping_matrix_vec_multiply<const M: usize, const N: usize, T: Integer>(matrix: &[[T; N]; M], vector: &[T; N]) -> [T; M] {
+    let mut result = [T::zero(); M];
+    for (r, row) in result.iter_mut().zip(matrix) {
+        for (a, b) in row.iter().zip(vector) {
+            *r = r.wrapping_add(a.wrapping_mul(*b));
+        }
+    }
+    result
+}
+
+/// Saturating Matrix Vector Multiplication
+///
+/// Multiply the vector by the matrix, computing each element exactly and
+/// clamping it to the bounds of the type once, so the result does not depend on
+/// the order of the terms.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::saturating_matrix_vec_multiply;
+///
+/// let matrix = [[100u8, 100], [1, 2]];
+/// let vector = [2, 1];
+/// assert_eq!(saturating_matrix_vec_multiply(&matrix, &vector), [255, 4]);
+/// assert_eq!(saturating_matrix_vec_multiply(&[[1u8, 2], [3, 4]], &[1, 1]), [3, 7]);
+/// // The exact sum fits even though the first two terms alone do not.
+/// assert_eq!(saturating_matrix_vec_multiply(&[[100i8, 100, -100]], &[1, 1, 1]), [100]);
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Returns
+///
+/// The result with every element saturated.
+pub fn saturating_matrix_vec_multiply<const M: usize, const N: usize, T: Integer>(matrix: &[[T; N]; M], vector: &[T; N]) -> [T; M] {
+    let mut result = [T::zero(); M];
+    for (r, row) in result.iter_mut().zip(matrix) {
+        *r = T::saturating_dot(row, vector);
+    }
+    result
+}
+
+/// Overflowing Matrix Vector Multiplication
+///
+/// Multiply the vector by the matrix, wrapping on overflow and reporting
+/// whether any operation overflowed.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::overflowing_matrix_vec_multiply;
+///
+/// let matrix = [[100u8, 100], [1, 2]];
+/// let vector = [2, 1];
+/// assert_eq!(overflowing_matrix_vec_multiply(&matrix, &vector), ([44, 4], true));
+/// assert_eq!(overflowing_matrix_vec_multiply(&[[1u8, 2], [3, 4]], &[1, 1]), ([3, 7], false));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to multiply.
+/// - `vector`: The vector to multiply with the matrix.
+///
+/// # Returns
+///
+/// The wrapped result and whether any operation overflowed.
+pub fn overflowing_matrix_vec_multiply<const M: usize, const N: usize, T: Integer>(matrix: &[[T; N]; M], vector: &[T; N]) -> ([T; M], bool) {
+    let mut overflowed = false;
+    let mut result = [T::zero(); M];
+    for (r, row) in result.iter_mut().zip(matrix) {
+        for (a, b) in row.iter().zip(vector) {
+            let (product, mul_overflow) = a.overflowing_mul(*b);
+            let (sum, add_overflow) = r.overflowing_add(product);
+            *r = sum;
+            overflowed |= mul_overflow | add_overflow;
+        }
+    }
+    (result, overflowed)
+}
+
+/// The exact sum of the products of corresponding elements of `a` and `b`,
+/// as a sign and a magnitude, where `split` gives the sign and magnitude of
+/// an element. Magnitudes that need more than 128 bits are reported as
+/// `u128::MAX`, which is beyond the bounds of every signed type and at the
+/// bound of `u128`.
+pub(crate) fn exact_dot<T: Copy>(a: &[T], b: &[T], split: impl Fn(T) -> (bool, u128)) -> (bool, u128) {
+    // 384-bit sums of the positive and the negative products, most
+    // significant word first, which no slice is long enough to overflow.
+    let mut positive = [0u128; 3];
+    let mut negative = [0u128; 3];
+    for (&x, &y) in a.iter().zip(b) {
+        let ((x_negative, x), (y_negative, y)) = (split(x), split(y));
+        let sum = if x_negative == y_negative { &mut positive } else { &mut negative };
+        let (high, low) = widening_mul(x, y);
+        let (low, carry) = sum[2].overflowing_add(low);
+        let (high, carry_a) = sum[1].overflowing_add(high);
+        let (high, carry_b) = high.overflowing_add(carry as u128);
+        *sum = [sum[0] + (carry_a | carry_b) as u128, high, low];
+    }
+    let (is_negative, larger, smaller) = if negative > positive { (true, negative, positive) } else { (false, positive, negative) };
+    let (low, borrow) = larger[2].overflowing_sub(smaller[2]);
+    let (high, borrow_a) = larger[1].overflowing_sub(smaller[1]);
+    let (high, borrow_b) = high.overflowing_sub(borrow as u128);
+    let top = larger[0] - smaller[0] - (borrow_a | borrow_b) as u128;
+    if top != 0 || high != 0 {
+        (is_negative, u128::MAX)
+    } else {
+        (is_negative, low)
+    }
+}
+
+/// The full 256-bit product of `a` and `b` as its high and low words.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let mask = u128::from(u64::MAX);
+    let (a_high, a_low) = (a >> 64, a & mask);
+    let (b_high, b_low) = (b >> 64, b & mask);
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let middle = (low_low >> 64) + (high_low & mask) + (low_high & mask);
+    let low = (middle << 64) | (low_low & mask);
+    let high = a_high * b_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    (high, low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(checked_add(&[i8::MAX, 0], &[1, 0]), None);
+        assert_eq!(checked_sub(&[i8::MIN, 0], &[1, 0]), None);
+        assert_eq!(checked_scale(&[64i8, 1], &2), None);
+        assert_eq!(checked_matrix_vec_multiply(&[[1i8, 2], [3, 4]], &[5, 6]), Some([17, 39]));
+        assert_eq!(checked_matrix_vec_multiply(&[[100i8, 100]], &[1, 1]), None);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(wrapping_add(&[u8::MAX, 1], &[1, 1]), [0, 2]);
+        assert_eq!(wrapping_sub(&[0u8, 1], &[1, 1]), [255, 0]);
+        assert_eq!(wrapping_scale(&[128u8, 1], &2), [0, 2]);
+        assert_eq!(wrapping_matrix_vec_multiply(&[[200u8, 100]], &[1, 1]), [44]);
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(saturating_add(&[i16::MAX, i16::MIN], &[1, -1]), [i16::MAX, i16::MIN]);
+        assert_eq!(saturating_sub(&[0u32, 5], &[1, 1]), [0, 4]);
+        assert_eq!(saturating_scale(&[-100i8, 10], &2), [i8::MIN, 20]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[-100i8, -100], [1, 1]], &[1, 1]), [i8::MIN, 2]);
+    }
+
+    #[test]
+    fn test_saturating_matrix_vec_multiply_is_exact() {
+        // Saturating after every term would give 27 and -28.
+        assert_eq!(saturating_matrix_vec_multiply(&[[100i8, 100, -100], [-100, -100, 100]], &[1, 1, 1]), [100, -100]);
+        // Every product overflows on its own.
+        assert_eq!(saturating_matrix_vec_multiply(&[[64i8, -85, 1]], &[4, 3, 1]), [2]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[i8::MIN, i8::MIN]], &[i8::MIN, -1]), [i8::MAX]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[i128::MAX, i128::MAX, 5]], &[2, -2, 1]), [5]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[i128::MIN, 1]], &[i128::MIN, i128::MIN]), [i128::MAX]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[i128::MIN, i128::MIN]], &[1, 1]), [i128::MIN]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[u128::MAX, u128::MAX]], &[u128::MAX, 1]), [u128::MAX]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[u64::MAX, 1]], &[u64::MAX, 1]), [u64::MAX]);
+        assert_eq!(saturating_matrix_vec_multiply(&[[3u8, 4]], &[5, 6]), [39]);
+    }
+
+    #[test]
+    fn test_overflowing() {
+        assert_eq!(overflowing_add(&[1u8, 2], &[3, 4]), ([4, 6], false));
+        assert_eq!(overflowing_sub(&[1u8, 2], &[3, 1]), ([254, 1], true));
+        assert_eq!(overflowing_scale(&[1u8, 200], &2), ([2, 144], true));
+        assert_eq!(overflowing_matrix_vec_multiply(&[[16u8, 0], [0, 1]], &[16, 1]), ([0, 1], true));
+    }
+}
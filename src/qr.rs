@@ -0,0 +1,447 @@
+use crate::{norm::scaled_norm, ComplexField, RealField, VectorError, Zero};
+
+/// QR Decomposition
+///
+/// The factorization `A = Q R` of an `M` by `N` matrix with `M >= N`, where
+/// `Q` is unitary, or orthogonal for a real matrix, and `R` is upper
+/// triangular, computed with Householder reflections. `Q` is stored
+/// implicitly as the reflections, so applying it to a vector costs no more
+/// than the factorization itself.
+///
+/// Using a matrix with fewer rows than columns fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Qr;
+///
+/// // Fit y = c0 + c1 x to the points (0, 6), (1, 0) and (2, 0).
+/// let qr = Qr::new(&[[1.0_f64, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+/// let (solution, residual) = qr.solve(&[6.0, 0.0, 0.0]).unwrap();
+/// assert!((solution[0] - 5.0).abs() < 1e-12 && (solution[1] + 3.0).abs() < 1e-12);
+/// assert!((residual - 6.0_f64.sqrt()).abs() < 1e-12);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Qr<T, const M: usize, const N: usize> {
+    qr: [[T; N]; M],
+    tau: [T; N],
+}
+
+impl<T: ComplexField, const M: usize, const N: usize> Qr<T, M, N> {
+    /// Factor `matrix`.
+    pub fn new(matrix: &[[T; N]; M]) -> Self {
+        const { assert!(M >= N, "QR decomposition needs at least as many rows as columns") };
+        let mut qr = *matrix;
+        let mut tau = [T::zero(); N];
+        householder_in_place(qr.as_flattened_mut(), &mut tau, None, M, N);
+        Self { qr, tau }
+    }
+
+    /// The full `M` by `M` unitary factor `Q`.
+    pub fn q(&self) -> [[T; M]; M] {
+        let mut q = crate::identity();
+        form_q(self.qr.as_flattened(), &self.tau, q.as_flattened_mut(), M, N, M);
+        q
+    }
+
+    /// The first `N` columns of `Q`, which span the column space of a matrix
+    /// with full column rank.
+    pub fn thin_q(&self) -> [[T; N]; M] {
+        let mut q = crate::from_fn(|i, j| if i == j { T::one() } else { T::zero() });
+        form_q(self.qr.as_flattened(), &self.tau, q.as_flattened_mut(), M, N, N);
+        q
+    }
+
+    /// The `N` by `N` upper triangular factor `R`.
+    pub fn r(&self) -> [[T; N]; N] {
+        crate::from_fn(|i, j| if i <= j { self.qr[i][j] } else { T::zero() })
+    }
+
+    /// Multiply `b` by `Qᴴ`, which is `Qᵀ` for a real matrix.
+    pub fn q_transpose_mul(&self, b: &[T; M]) -> [T; M] {
+        let mut result = *b;
+        apply_q_transpose(self.qr.as_flattened(), &self.tau, &mut result, M, N);
+        result
+    }
+
+    /// Whether every diagonal element of `R` is nonzero relative to the
+    /// largest one.
+    pub fn is_full_rank(&self) -> bool {
+        triangular_rank(self.qr.as_flattened(), M, N) == N
+    }
+
+    /// Solve `A x = b` in the least-squares sense, minimizing the Euclidean
+    /// norm of `b - A x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::Singular`] if the matrix does not have full
+    /// column rank; use [`ColPivQr`] for rank-deficient problems.
+    ///
+    /// # Returns
+    ///
+    /// The solution and the norm of the residual `b - A x`.
+    pub fn solve(&self, b: &[T; M]) -> Result<([T; N], T::Real), VectorError> {
+        if !self.is_full_rank() {
+            return Err(VectorError::Singular);
+        }
+        let c = self.q_transpose_mul(b);
+        let mut solution: [T; N] = core::array::from_fn(|i| c[i]);
+        back_substitute(self.qr.as_flattened(), &mut solution, N, N);
+        Ok((solution, residual_norm(&c[N..])))
+    }
+}
+
+/// Column-Pivoted QR Decomposition
+///
+/// The factorization `A P = Q R` of an `M` by `N` matrix with `M >= N`,
+/// where `P` moves the column with the largest remaining norm to the front
+/// at each step. The diagonal of `R` then decreases in magnitude, which
+/// reveals the numerical rank of the matrix.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::ColPivQr;
+///
+/// let qr = ColPivQr::new(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+/// assert_eq!(qr.rank(), 2);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColPivQr<T, const M: usize, const N: usize> {
+    qr: [[T; N]; M],
+    tau: [T; N],
+    permutation: [usize; N],
+    rank: usize,
+}
+
+impl<T: ComplexField, const M: usize, const N: usize> ColPivQr<T, M, N> {
+    /// Factor `matrix`.
+    pub fn new(matrix: &[[T; N]; M]) -> Self {
+        const { assert!(M >= N, "QR decomposition needs at least as many rows as columns") };
+        let mut qr = *matrix;
+        let mut tau = [T::zero(); N];
+        let mut permutation = core::array::from_fn(|j| j);
+        householder_in_place(qr.as_flattened_mut(), &mut tau, Some(&mut permutation), M, N);
+        let rank = triangular_rank(qr.as_flattened(), M, N);
+        Self { qr, tau, permutation, rank }
+    }
+
+    /// The full `M` by `M` unitary factor `Q`.
+    pub fn q(&self) -> [[T; M]; M] {
+        let mut q = crate::identity();
+        form_q(self.qr.as_flattened(), &self.tau, q.as_flattened_mut(), M, N, M);
+        q
+    }
+
+    /// The `N` by `N` upper triangular factor `R`.
+    pub fn r(&self) -> [[T; N]; N] {
+        crate::from_fn(|i, j| if i <= j { self.qr[i][j] } else { T::zero() })
+    }
+
+    /// The column permutation: column `j` of `A P` is column
+    /// `permutation()[j]` of `A`.
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    /// The number of diagonal elements of `R` that are nonzero relative to
+    /// the largest one.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Solve `A x = b` in the least-squares sense. When the matrix is rank
+    /// deficient, this is the basic solution with a zero for every column
+    /// beyond the rank, not the minimum-norm solution.
+    ///
+    /// # Returns
+    ///
+    /// The solution and the norm of the residual `b - A x`.
+    pub fn solve(&self, b: &[T; M]) -> ([T; N], T::Real) {
+        let mut c = *b;
+        apply_q_transpose(self.qr.as_flattened(), &self.tau, &mut c, M, N);
+        let mut z: [T; N] = core::array::from_fn(|i| if i < self.rank { c[i] } else { T::zero() });
+        back_substitute(self.qr.as_flattened(), &mut z, N, self.rank);
+        let mut solution = [T::zero(); N];
+        for (value, &column) in z.iter().zip(&self.permutation) {
+            solution[column] = *value;
+        }
+        (solution, residual_norm(&c[self.rank..]))
+    }
+}
+
+/// Least Squares
+///
+/// Find the `x` that minimizes the Euclidean norm of `b - A x` for a matrix
+/// with at least as many rows as columns, using [`Qr`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::lstsq;
+///
+/// let a = [[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]];
+/// assert_eq!(lstsq(&a, &[3.0, 4.0, 12.0]), Ok(([3.0, 4.0], 12.0)));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix `A`.
+/// - `vec`: The right-hand side `b`.
+///
+/// # Errors
+///
+/// Returns [`VectorError::Singular`] if the matrix does not have full column
+/// rank.
+///
+/// # Returns
+///
+/// The solution and the norm of the residual `b - A x`.
+pub fn lstsq<const M: usize, const N: usize, T: ComplexField>(
+    matrix: &[[T; N]; M],
+    vec: &[T; M],
+) -> Result<([T; N], T::Real), VectorError> {
+    Qr::new(matrix).solve(vec)
+}
+
+/// Reduce the `m` by `n` row-major matrix in `a` to upper triangular form
+/// with Householder reflections. Each reflection `H = I - tau v vᴴ` is
+/// stored with `v[0] = 1` implied and the rest of `v` below the diagonal,
+/// and `Hᴴ` maps its column to a real multiple of the first standard basis
+/// vector. When `permutation` is given, columns are pivoted by their
+/// remaining norm.
+pub(crate) fn householder_in_place<T: ComplexField>(
+    a: &mut [T],
+    tau: &mut [T],
+    mut permutation: Option<&mut [usize]>,
+    m: usize,
+    n: usize,
+) {
+    for k in 0..n.min(m) {
+        if let Some(permutation) = permutation.as_deref_mut() {
+            let column_norm = |j: usize| scaled_norm((k..m).map(|i| a[i * n + j]));
+            let mut best = k;
+            let mut best_norm = column_norm(k);
+            for j in k + 1..n {
+                let norm = column_norm(j);
+                if norm > best_norm {
+                    best = j;
+                    best_norm = norm;
+                }
+            }
+            if best != k {
+                for i in 0..m {
+                    a.swap(i * n + k, i * n + best);
+                }
+                permutation.swap(k, best);
+            }
+        }
+        let alpha = a[k * n + k];
+        if (k + 1..m).all(|i| a[i * n + k].is_zero()) {
+            tau[k] = T::zero();
+            continue;
+        }
+        let length = scaled_norm((k..m).map(|i| a[i * n + k]));
+        let beta = T::from_real(if alpha.real() < T::Real::zero() { length } else { -length });
+        tau[k] = (beta - alpha) / beta;
+        let scale = (alpha - beta).recip();
+        for i in k + 1..m {
+            a[i * n + k] *= scale;
+        }
+        a[k * n + k] = beta;
+        for j in k + 1..n {
+            let w = (k + 1..m).fold(a[k * n + j], |sum, i| sum + a[i * n + k].conj() * a[i * n + j]) * tau[k].conj();
+            a[k * n + j] -= w;
+            for i in k + 1..m {
+                let v = a[i * n + k];
+                a[i * n + j] -= v * w;
+            }
+        }
+    }
+}
+
+/// Apply the stored reflections to the length `m` vector `b`, giving `Qᴴ b`.
+pub(crate) fn apply_q_transpose<T: ComplexField>(qr: &[T], tau: &[T], b: &mut [T], m: usize, n: usize) {
+    for k in 0..n.min(m) {
+        let w = (k + 1..m).fold(b[k], |sum, i| sum + qr[i * n + k].conj() * b[i]) * tau[k].conj();
+        b[k] -= w;
+        for i in k + 1..m {
+            b[i] -= qr[i * n + k] * w;
+        }
+    }
+}
+
+/// Overwrite the `m` by `columns` row-major matrix `q`, which must start as
+/// the first `columns` columns of the identity, with the same columns of `Q`.
+pub(crate) fn form_q<T: ComplexField>(qr: &[T], tau: &[T], q: &mut [T], m: usize, n: usize, columns: usize) {
+    for k in (0..n.min(m)).rev() {
+        for c in 0..columns {
+            let w = (k + 1..m).fold(q[k * columns + c], |sum, i| sum + qr[i * n + k].conj() * q[i * columns + c]) * tau[k];
+            q[k * columns + c] -= w;
+            for i in k + 1..m {
+                q[i * columns + c] -= qr[i * n + k] * w;
+            }
+        }
+    }
+}
+
+/// The number of diagonal elements of the upper triangle of the `m` by `n`
+/// row-major matrix `r` that are nonzero relative to the largest one.
+pub(crate) fn triangular_rank<T: ComplexField>(r: &[T], m: usize, n: usize) -> usize {
+    let size = n.min(m);
+    let largest = (0..size).fold(T::Real::zero(), |max, i| max.max(r[i * n + i].modulus()));
+    let tolerance = largest * T::Real::epsilon() * T::Real::from_f64(m.max(n) as f64);
+    (0..size).filter(|&i| r[i * n + i].modulus() > tolerance).count()
+}
+
+/// Solve the leading `rank` by `rank` upper triangle of `r`, whose rows are
+/// `n` wide, in place in `x`.
+pub(crate) fn back_substitute<T: ComplexField>(r: &[T], x: &mut [T], n: usize, rank: usize) {
+    for i in (0..rank).rev() {
+        let sum = (i + 1..rank).fold(x[i], |sum, j| sum - r[i * n + j] * x[j]);
+        x[i] = sum / r[i * n + i];
+    }
+}
+
+fn residual_norm<T: ComplexField>(tail: &[T]) -> T::Real {
+    scaled_norm(tail.iter().copied())
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{identity, matrix_multiply, matrix_vec_multiply, transpose};
+
+    fn assert_close<const M: usize, const N: usize>(a: &[[f64; N]; M], b: &[[f64; N]; M]) {
+        for (x, y) in a.as_flattened().iter().zip(b.as_flattened()) {
+            assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_factors() {
+        let matrix = [[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0], [1.0, 2.0, 3.0]];
+        let qr = Qr::new(&matrix);
+        let q = qr.q();
+        assert_close(&matrix_multiply(&transpose(&q), &q), &identity());
+        let thin = qr.thin_q();
+        assert_close(&matrix_multiply(&thin, &qr.r()), &matrix);
+        for (thin_row, full_row) in thin.iter().zip(&q) {
+            assert_close(&[*thin_row], &[[full_row[0], full_row[1], full_row[2]]]);
+        }
+        assert!(qr.r()[2][0] == 0.0 && qr.r()[1][0] == 0.0);
+        assert!(qr.is_full_rank());
+    }
+
+    #[test]
+    fn test_lstsq() {
+        let a = [[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let (solution, residual) = lstsq(&a, &[6.0, 0.0, 0.0]).unwrap();
+        assert_close(&[solution], &[[5.0, -3.0]]);
+        assert!((residual - 6.0_f64.sqrt()).abs() < 1e-12);
+        // A square system is solved exactly.
+        let square = [[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]];
+        let (solution, residual) = lstsq(&square, &[8.0, -11.0, -3.0]).unwrap();
+        assert_close(&[solution], &[[2.0, 3.0, -1.0]]);
+        assert!(residual < 1e-12);
+        assert_eq!(lstsq(&[[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]], &[1.0, 2.0, 3.0]), Err(VectorError::Singular));
+    }
+
+    #[test]
+    fn test_column_pivoted() {
+        let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0], [10.0, 11.0, 12.0]];
+        let qr = ColPivQr::new(&matrix);
+        assert_eq!(qr.rank(), 2);
+        assert_eq!(qr.permutation(), &[2, 0, 1]);
+        let permuted: [[f64; 3]; 4] = crate::from_fn(|i, j| matrix[i][qr.permutation()[j]]);
+        let q = qr.q();
+        let r = qr.r();
+        let thin: [[f64; 3]; 4] = crate::from_fn(|i, j| q[i][j]);
+        assert_close(&matrix_multiply(&thin, &r), &permuted);
+        assert!(r[0][0].abs() >= r[1][1].abs() && r[1][1].abs() >= r[2][2].abs());
+        // A consistent rank-deficient system is still solved exactly.
+        let b = matrix_vec_multiply(&matrix, &[1.0, 1.0, 1.0]);
+        let (solution, residual) = qr.solve(&b);
+        assert_close(&[matrix_vec_multiply(&matrix, &solution)], &[b]);
+        assert!(residual < 1e-10);
+        assert_eq!(ColPivQr::new(&[[1.0, 0.0], [0.0, 1.0]]).rank(), 2);
+        assert_eq!(ColPivQr::new(&[[0.0_f32; 2]; 3]).rank(), 0);
+    }
+
+    #[test]
+    fn test_extreme_magnitudes() {
+        let matrices = [[[2e160, 1e160], [1e160, 3e160]], [[3e-170, 1.0], [4e-170, 2.0]], [[3e-170, 0.0], [4e-170, 1e-170]]];
+        for matrix in matrices {
+            let qr = Qr::new(&matrix);
+            assert_close(&matrix_multiply(&transpose(&qr.q()), &qr.q()), &identity());
+            let product = matrix_multiply(&qr.q(), &qr.r());
+            // Householder QR is accurate relative to each column.
+            for j in 0..2 {
+                let scale = matrix.iter().fold(0.0_f64, |max, row| max.max(row[j].abs()));
+                for (row, expected) in product.iter().zip(&matrix) {
+                    assert!((row[j] - expected[j]).abs() <= 1e-14 * scale, "{product:?} != {matrix:?}");
+                }
+            }
+        }
+        let r: [[f64; 2]; 2] = Qr::new(&[[3e-170, 1.0], [4e-170, 2.0]]).r();
+        assert!((r[0][0].abs() / 5e-170 - 1.0).abs() < 1e-15);
+        let (solution, residual) = lstsq(&[[1e160], [0.0]], &[2e160, 3e160]).unwrap();
+        assert_eq!(solution, [2.0]);
+        assert_eq!(residual, 3e160);
+    }
+
+    #[test]
+    fn test_complex() {
+        use crate::{conjugate_transpose, norm_l2, Complex};
+        let c = Complex::new;
+        let matrix = [[c(1.0, 1.0), c(0.0, 2.0)], [c(2.0, 0.0), c(1.0, -1.0)], [c(0.0, -1.0), c(3.0, 0.0)]];
+        let qr = Qr::new(&matrix);
+        let q = qr.q();
+        for (x, y) in matrix_multiply(&conjugate_transpose(&q), &q).as_flattened().iter().zip(identity::<3, Complex<f64>>().as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        for (x, y) in matrix_multiply(&qr.thin_q(), &qr.r()).as_flattened().iter().zip(matrix.as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        // A consistent system is solved exactly.
+        let expected = [c(1.0, -1.0), c(0.5, 2.0)];
+        let (solution, residual) = lstsq(&matrix, &matrix_vec_multiply(&matrix, &expected)).unwrap();
+        for (x, y) in solution.iter().zip(&expected) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        assert!(residual < 1e-12);
+        // Otherwise the residual is orthogonal to the columns.
+        let b = [c(1.0, 0.0), c(0.0, 0.0), c(0.0, 1.0)];
+        let (solution, residual) = lstsq(&matrix, &b).unwrap();
+        let difference = crate::sub(&b, &matrix_vec_multiply(&matrix, &solution));
+        assert!((norm_l2(&difference) - residual).abs() < 1e-12);
+        for value in matrix_vec_multiply(&conjugate_transpose(&matrix), &difference) {
+            assert!(value.modulus() < 1e-12);
+        }
+        assert_eq!(ColPivQr::new(&[[c(1.0, 1.0), c(2.0, 2.0)], [c(0.0, 1.0), c(0.0, 2.0)]]).rank(), 1);
+    }
+}
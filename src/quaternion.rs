@@ -0,0 +1,415 @@
+use core::ops::{Mul, MulAssign, Neg};
+
+use crate::{
+    cross, dot, euler::intrinsic, rotation_matrix_to_euler, try_normalize, EulerFrame, EulerOrder, Field, Matrix, RealField, Ring, Vector,
+    VectorError,
+};
+
+/// Quaternion
+///
+/// A number `w + x i + y j + z k`. Unit quaternions represent rotations in
+/// three dimensions: multiplying two composes their rotations, and unlike a
+/// product of rotation matrices the result is cheap to renormalize, so long
+/// chains of rotations do not drift away from being rotations.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Quaternion;
+///
+/// let quarter_turn = Quaternion::from_axis_angle(&[0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2).unwrap();
+/// let rotated = quarter_turn.rotate(&[1.0, 0.0, 0.0]);
+/// assert!((rotated[0] - 0.0_f64).abs() < 1e-15 && (rotated[1] - 1.0_f64).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Quaternion<T> {
+    /// The scalar part.
+    pub w: T,
+    /// The coefficient of `i`.
+    pub x: T,
+    /// The coefficient of `j`.
+    pub y: T,
+    /// The coefficient of `k`.
+    pub z: T,
+}
+
+impl<T> Quaternion<T> {
+    /// Create a quaternion from its scalar part and the coefficients of `i`,
+    /// `j` and `k`.
+    pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+}
+
+impl<T: Ring> Quaternion<T> {
+    /// The identity rotation `1`.
+    pub fn identity() -> Self {
+        Self::from_parts(T::one(), [T::zero(); 3])
+    }
+
+    /// Create a quaternion from its scalar and vector parts.
+    pub fn from_parts(w: T, vector: [T; 3]) -> Self {
+        let [x, y, z] = vector;
+        Self { w, x, y, z }
+    }
+
+    /// The vector part `[x, y, z]`.
+    pub fn vector(&self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// The four-dimensional dot product of the components.
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + dot(&self.vector(), &other.vector())
+    }
+
+    /// The square of the norm, `w² + x² + y² + z²`.
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Rotate `vec` by this quaternion, which must have unit norm.
+    pub fn rotate(&self, vec: &[T; 3]) -> [T; 3] {
+        // v + 2w (u × v) + 2u × (u × v), with u the vector part.
+        let u = self.vector();
+        let doubled = cross(&u, vec).map(|value| value + value);
+        let twisted = cross(&u, &doubled);
+        core::array::from_fn(|i| vec[i] + self.w * doubled[i] + twisted[i])
+    }
+
+    fn combine(&self, a: T, other: &Self, b: T) -> Self {
+        Self {
+            w: self.w * a + other.w * b,
+            x: self.x * a + other.x * b,
+            y: self.y * a + other.y * b,
+            z: self.z * a + other.z * b,
+        }
+    }
+}
+
+impl<T: Ring + Neg<Output = T>> Quaternion<T> {
+    /// The conjugate `w - x i - y j - z k`, which is the inverse rotation of
+    /// a unit quaternion.
+    pub fn conj(&self) -> Self {
+        Self { w: self.w, x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl<T: Field> Quaternion<T> {
+    /// The multiplicative inverse, the conjugate divided by the squared norm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::ZeroVector`] if the quaternion is zero.
+    pub fn inverse(&self) -> Result<Self, VectorError> {
+        let norm_sqr = self.norm_sqr();
+        if norm_sqr.is_zero() {
+            return Err(VectorError::ZeroVector);
+        }
+        Ok(self.conj().divide(norm_sqr))
+    }
+
+    fn divide(&self, divisor: T) -> Self {
+        Self { w: self.w / divisor, x: self.x / divisor, y: self.y / divisor, z: self.z / divisor }
+    }
+}
+
+impl<T: RealField> Quaternion<T> {
+    /// The rotation by `angle` radians about `axis`, counterclockwise when
+    /// looking down the axis towards the origin. The axis does not need to
+    /// have unit length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::ZeroVector`] if the axis is the zero vector.
+    pub fn from_axis_angle(axis: &[T; 3], angle: T) -> Result<Self, VectorError> {
+        let axis = try_normalize(axis)?;
+        let half = angle * T::from_f64(0.5);
+        Ok(Self::from_parts(half.cos(), axis.map(|value| value * half.sin())))
+    }
+
+    /// The unit axis and the angle in `[0, π]` of the rotation. The identity
+    /// rotation has no axis and reports `[1, 0, 0]`.
+    pub fn to_axis_angle(&self) -> ([T; 3], T) {
+        let unit = self.normalize();
+        let unit = if unit.w < T::zero() { -unit } else { unit };
+        let vector = unit.vector();
+        let sine = crate::norm_l2(&vector);
+        let angle = sine.atan2(unit.w) * T::from_f64(2.0);
+        if sine.is_zero() {
+            ([T::one(), T::zero(), T::zero()], angle)
+        } else {
+            (vector.map(|value| value / sine), angle)
+        }
+    }
+
+    /// The rotation of three Euler angles applied in `order`. See
+    /// [`euler_to_rotation_matrix`](crate::euler_to_rotation_matrix).
+    pub fn from_euler(angles: &[T; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        let (angles, order) = intrinsic(angles, order, frame);
+        order.axes().iter().zip(angles).fold(Self::identity(), |product, (axis, angle)| {
+            let half = angle * T::from_f64(0.5);
+            let mut vector = [T::zero(); 3];
+            vector[*axis] = half.sin();
+            product * Self::from_parts(half.cos(), vector)
+        })
+    }
+
+    /// The Euler angles of the rotation in `order`. See
+    /// [`rotation_matrix_to_euler`] for their ranges and the handling of
+    /// gimbal lock.
+    pub fn to_euler(&self, order: EulerOrder, frame: EulerFrame) -> [T; 3] {
+        rotation_matrix_to_euler(&self.to_rotation_matrix(), order, frame)
+    }
+
+    /// The unit quaternion of the rotation matrix `matrix`. The result is
+    /// normalized, so a matrix that has drifted slightly from being a
+    /// rotation gives the nearest rotation's quaternion.
+    pub fn from_rotation_matrix(matrix: &[[T; 3]; 3]) -> Self {
+        let m = matrix;
+        let one = T::one();
+        let quarter = T::from_f64(0.25);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        // Divide by the largest of the four possible denominators.
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * T::from_f64(2.0);
+            Self::new(s * quarter, (m[2][1] - m[1][2]) / s, (m[0][2] - m[2][0]) / s, (m[1][0] - m[0][1]) / s)
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * T::from_f64(2.0);
+            Self::new((m[2][1] - m[1][2]) / s, s * quarter, (m[0][1] + m[1][0]) / s, (m[0][2] + m[2][0]) / s)
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * T::from_f64(2.0);
+            Self::new((m[0][2] - m[2][0]) / s, (m[0][1] + m[1][0]) / s, s * quarter, (m[1][2] + m[2][1]) / s)
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * T::from_f64(2.0);
+            Self::new((m[1][0] - m[0][1]) / s, (m[0][2] + m[2][0]) / s, (m[1][2] + m[2][1]) / s, s * quarter)
+        };
+        q.normalize()
+    }
+
+    /// The rotation matrix of the quaternion, which is normalized first.
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let Self { w, x, y, z } = *self;
+        let one = T::one();
+        let s = T::from_f64(2.0) / self.norm_sqr();
+        [
+            [one - s * (y * y + z * z), s * (x * y - w * z), s * (x * z + w * y)],
+            [s * (x * y + w * z), one - s * (x * x + z * z), s * (y * z - w * x)],
+            [s * (x * z - w * y), s * (y * z + w * x), one - s * (x * x + y * y)],
+        ]
+    }
+
+    /// The norm `√(w² + x² + y² + z²)`.
+    pub fn norm(&self) -> T {
+        self.norm_sqr().sqrt()
+    }
+
+    /// Scale to unit norm. The zero quaternion produces NaN components; use
+    /// [`Quaternion::try_normalize`] to detect it instead.
+    pub fn normalize(&self) -> Self {
+        self.divide(self.norm())
+    }
+
+    /// Scale to unit norm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::ZeroVector`] if the quaternion is zero.
+    pub fn try_normalize(&self) -> Result<Self, VectorError> {
+        if self.norm_sqr().is_zero() {
+            Err(VectorError::ZeroVector)
+        } else {
+            Ok(self.normalize())
+        }
+    }
+
+    /// Spherical linear interpolation from `self` at `t = 0` to `other` at
+    /// `t = 1` along the shorter arc, at constant angular velocity. Both
+    /// quaternions must have unit norm.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let (other, cosine) = self.nearer(other);
+        // Nearly equal rotations make the sine below vanish.
+        if cosine > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(&other, t);
+        }
+        let angle = cosine.acos();
+        let sine = angle.sin();
+        let a = ((T::one() - t) * angle).sin() / sine;
+        let b = (t * angle).sin() / sine;
+        self.combine(a, &other, b)
+    }
+
+    /// Normalized linear interpolation from `self` at `t = 0` to `other` at
+    /// `t = 1` along the shorter arc. Cheaper than [`Quaternion::slerp`] and
+    /// follows the same path, but not at constant angular velocity.
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let (other, _) = self.nearer(other);
+        self.combine(T::one() - t, &other, t).normalize()
+    }
+
+    /// `other` or its negation, whichever is the same rotation on the near
+    /// side of `self`, along with its dot product with `self`.
+    fn nearer(&self, other: &Self) -> (Self, T) {
+        let cosine = self.dot(other);
+        if cosine < T::zero() {
+            (-*other, -cosine)
+        } else {
+            (*other, cosine)
+        }
+    }
+}
+
+impl<T: Ring> Mul for Quaternion<T> {
+    type Output = Self;
+
+    /// The Hamilton product, which applies `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+}
+
+impl<T: Ring> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Ring> Mul<Vector<T, 3>> for Quaternion<T> {
+    type Output = Vector<T, 3>;
+
+    /// Rotate a vector. See [`Quaternion::rotate`].
+    fn mul(self, rhs: Vector<T, 3>) -> Vector<T, 3> {
+        Vector::new(self.rotate(rhs.as_array()))
+    }
+}
+
+impl<T: RealField> From<Quaternion<T>> for Matrix<T, 3, 3> {
+    /// The rotation matrix. See [`Quaternion::to_rotation_matrix`].
+    fn from(quaternion: Quaternion<T>) -> Self {
+        Matrix::from_rows(quaternion.to_rotation_matrix())
+    }
+}
+
+impl<T: Ring + Neg<Output = T>> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { w: -self.w, x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{matrix_multiply, matrix_vec_multiply, transpose};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close<const N: usize>(a: &[f64; N], b: &[f64; N]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
+
+    fn components(q: &Quaternion<f64>) -> [f64; 4] {
+        [q.w, q.x, q.y, q.z]
+    }
+
+    #[test]
+    fn test_algebra() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let k = Quaternion::new(0, 0, 0, 1);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * j * k, -Quaternion::identity());
+        let q = Quaternion::new(1.0, 2.0, -1.0, 3.0);
+        assert_eq!(q * q.conj(), Quaternion::new(15.0, 0.0, 0.0, 0.0));
+        assert_close(&components(&(q * q.inverse().unwrap())), &[1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), Err(VectorError::ZeroVector));
+        assert_eq!(Quaternion::new(0.0, 3.0, 0.0, 4.0).normalize(), Quaternion::new(0.0, 0.6, 0.0, 0.8));
+        assert_eq!(Quaternion::<f64>::default().try_normalize(), Err(VectorError::ZeroVector));
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let q = Quaternion::from_axis_angle(&[0.0, 0.0, 2.0], FRAC_PI_2).unwrap();
+        assert_close(&q.rotate(&[1.0, 0.0, 0.0]), &[0.0, 1.0, 0.0]);
+        assert_close((q * Vector::new([0.0, 1.0, 5.0])).as_array(), &[-1.0, 0.0, 5.0]);
+        let (axis, angle) = q.to_axis_angle();
+        assert_close(&axis, &[0.0, 0.0, 1.0]);
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+        // The negated quaternion is the same rotation.
+        let (axis, angle) = (-q).to_axis_angle();
+        assert_close(&axis, &[0.0, 0.0, 1.0]);
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(Quaternion::<f64>::identity().to_axis_angle(), ([1.0, 0.0, 0.0], 0.0));
+        assert_eq!(Quaternion::from_axis_angle(&[0.0; 3], 1.0), Err(VectorError::ZeroVector));
+        // Composing rotations: a quarter turn about x, then one about z.
+        let about_x = Quaternion::from_axis_angle(&[1.0, 0.0, 0.0], FRAC_PI_2).unwrap();
+        assert_close(&(q * about_x).rotate(&[0.0, 1.0, 0.0]), &q.rotate(&about_x.rotate(&[0.0, 1.0, 0.0])));
+        assert_close(&(q * about_x).rotate(&[0.0, 1.0, 0.0]), &[0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        let axes = [[1.0, 2.0, 3.0], [1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 1.0, -1.0]];
+        for axis in &axes {
+            // Angles near π exercise every branch of the conversion.
+            for angle in [0.0, 0.3, FRAC_PI_2, 2.5, PI] {
+                let q = Quaternion::from_axis_angle(axis, angle).unwrap();
+                let matrix = q.to_rotation_matrix();
+                let v = [0.5, -1.0, 2.0];
+                assert_close(&matrix_vec_multiply(&matrix, &v), &q.rotate(&v));
+                assert_eq!(Matrix::from(q), Matrix::from_rows(matrix));
+                let identity = matrix_multiply(&matrix, &transpose(&matrix));
+                assert_close(identity.as_flattened().try_into().unwrap(), &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+                let back = Quaternion::from_rotation_matrix(&matrix);
+                let sign = if back.dot(&q) < 0.0 { -1.0 } else { 1.0 };
+                assert_close(&components(&back).map(|value| value * sign), &components(&q));
+            }
+        }
+    }
+
+    #[test]
+    fn test_euler() {
+        use crate::euler_to_rotation_matrix;
+        for order in EulerOrder::ALL {
+            for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic] {
+                let angles = [-0.7, 1.2, 0.4];
+                let q = Quaternion::from_euler(&angles, order, frame);
+                let matrix = euler_to_rotation_matrix(&angles, order, frame);
+                for (x, y) in q.to_rotation_matrix().iter().zip(&matrix) {
+                    assert_close(x, y);
+                }
+                assert_close(&q.to_euler(order, frame), &angles);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&[0.0, 1.0, 0.0], FRAC_PI_2).unwrap();
+        let halfway = Quaternion::from_axis_angle(&[0.0, 1.0, 0.0], FRAC_PI_2 / 2.0).unwrap();
+        assert_close(&components(&a.slerp(&b, 0.5)), &components(&halfway));
+        assert_close(&components(&a.nlerp(&b, 0.5)), &components(&halfway));
+        assert_close(&components(&a.slerp(&b, 0.0)), &components(&a));
+        assert_close(&components(&a.slerp(&b, 1.0)), &components(&b));
+        // The negation of `b` is the same rotation, so the path is unchanged.
+        assert_close(&components(&a.slerp(&-b, 0.5)), &components(&halfway));
+        let third = Quaternion::from_axis_angle(&[0.0, 1.0, 0.0], FRAC_PI_2 / 3.0).unwrap();
+        assert_close(&components(&a.slerp(&b, 1.0 / 3.0)), &components(&third));
+        assert_close(&components(&a.slerp(&a, 0.25)), &components(&a));
+    }
+}
@@ -0,0 +1,479 @@
+use crate::{norm::scaled_norm, Abs, ComplexField, Field, One, RealField, VectorError, Zero};
+
+/// The number of sweeps [`Svd::new`] performs before giving up.
+const DEFAULT_MAX_SWEEPS: usize = 64;
+
+/// Singular Value Decomposition
+///
+/// The factorization `A = U Σ Vᴴ` of an `M` by `N` matrix, where `U` and `V`
+/// are unitary, or orthogonal for a real matrix, and `Σ` is zero except for
+/// the `min(M, N)` singular values on its diagonal, which are real,
+/// nonnegative and in descending order. Computed with one-sided Jacobi
+/// rotations.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::Svd;
+///
+/// let svd = Svd::new(&[[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]]).unwrap();
+/// assert_eq!(svd.singular_values(), &[4.0, 3.0]);
+/// assert_eq!(svd.spectral_norm(), 4.0);
+/// assert_eq!(svd.pseudo_inverse(), [[1.0 / 3.0, 0.0, 0.0], [0.0, -0.25, 0.0]]);
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Svd<T: ComplexField, const M: usize, const N: usize> {
+    u: [[T; M]; M],
+    // Only the first `min(M, N)` elements are singular values.
+    singular_values: [T::Real; N],
+    v: [[T; N]; N],
+}
+
+impl<T: ComplexField, const M: usize, const N: usize> Svd<T, M, N> {
+    /// Decompose `matrix` to machine precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if the columns are not
+    /// orthogonal after 64 sweeps, which only happens for matrices with
+    /// non-finite elements.
+    pub fn new(matrix: &[[T; N]; M]) -> Result<Self, VectorError> {
+        Self::with_settings(matrix, T::Real::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Decompose `matrix`, treating two columns as orthogonal once their
+    /// cosine is at most `tolerance`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VectorError::NoConvergence`] if that has not happened after
+    /// `max_sweeps` sweeps over every pair of columns.
+    pub fn with_settings(matrix: &[[T; N]; M], tolerance: T::Real, max_sweeps: usize) -> Result<Self, VectorError> {
+        let mut u = crate::identity::<M, T>();
+        let mut v = crate::identity::<N, T>();
+        let mut singular_values = [T::Real::zero(); N];
+        let size = M.min(N);
+        if M >= N {
+            let mut work = *matrix;
+            one_sided_jacobi(
+                work.as_flattened_mut(),
+                u.as_flattened_mut(),
+                v.as_flattened_mut(),
+                &mut singular_values[..size],
+                M,
+                N,
+                tolerance,
+                max_sweeps,
+            )?;
+        } else {
+            // Decompose the conjugate transpose, whose factors are swapped.
+            let mut work = crate::conjugate_transpose(matrix);
+            one_sided_jacobi(
+                work.as_flattened_mut(),
+                v.as_flattened_mut(),
+                u.as_flattened_mut(),
+                &mut singular_values[..size],
+                N,
+                M,
+                tolerance,
+                max_sweeps,
+            )?;
+        }
+        Ok(Self { u, singular_values, v })
+    }
+
+    /// The `M` by `M` unitary factor `U`, whose columns are the left
+    /// singular vectors.
+    pub fn u(&self) -> [[T; M]; M] {
+        self.u
+    }
+
+    /// The `N` by `N` unitary factor `V`, whose columns are the right
+    /// singular vectors.
+    pub fn v(&self) -> [[T; N]; N] {
+        self.v
+    }
+
+    /// The `min(M, N)` singular values in descending order.
+    pub fn singular_values(&self) -> &[T::Real] {
+        &self.singular_values[..M.min(N)]
+    }
+
+    /// The `M` by `N` diagonal factor `Σ`.
+    pub fn sigma(&self) -> [[T; N]; M] {
+        crate::from_fn(|i, j| if i == j { T::from_real(self.singular_values[i]) } else { T::zero() })
+    }
+
+    /// The first `N` columns of `U`, which with the singular values and `V`
+    /// form the thin SVD of a matrix with at least as many rows as columns.
+    /// Using a matrix with fewer rows than columns fails to compile; use
+    /// [`Svd::thin_v`] instead.
+    pub fn thin_u(&self) -> [[T; N]; M] {
+        const { assert!(M >= N, "the thin U of a wide matrix is the full U") };
+        crate::from_fn(|i, j| self.u[i][j])
+    }
+
+    /// The first `M` columns of `V`, which with `U` and the singular values
+    /// form the thin SVD of a matrix with at least as many columns as rows.
+    /// Using a matrix with fewer columns than rows fails to compile; use
+    /// [`Svd::thin_u`] instead.
+    pub fn thin_v(&self) -> [[T; M]; N] {
+        const { assert!(M <= N, "the thin V of a tall matrix is the full V") };
+        crate::from_fn(|i, j| self.v[i][j])
+    }
+
+    /// The default cutoff below which singular values count as zero: the
+    /// largest singular value times machine epsilon times `max(M, N)`.
+    pub fn tolerance(&self) -> T::Real {
+        self.spectral_norm() * T::Real::epsilon() * T::Real::from_f64(M.max(N) as f64)
+    }
+
+    /// The number of singular values greater than `tolerance`.
+    pub fn rank(&self, tolerance: T::Real) -> usize {
+        self.singular_values().iter().filter(|&&sigma| sigma > tolerance).count()
+    }
+
+    /// The largest singular value, which is the operator 2-norm of the
+    /// matrix.
+    pub fn spectral_norm(&self) -> T::Real {
+        self.singular_values().first().copied().unwrap_or(T::Real::zero())
+    }
+
+    /// The ratio of the largest singular value to the smallest, which is
+    /// huge or infinite for a rank-deficient matrix.
+    pub fn condition_number(&self) -> T::Real {
+        match self.singular_values() {
+            [] => T::Real::one(),
+            values => values[0] / values[values.len() - 1],
+        }
+    }
+
+    /// The Moore-Penrose pseudo-inverse `V Σ⁺ Uᴴ`, inverting the singular
+    /// values above [`Svd::tolerance`] and zeroing the rest.
+    pub fn pseudo_inverse(&self) -> [[T; M]; N] {
+        let tolerance = self.tolerance();
+        crate::from_fn(|i, j| {
+            self.singular_values()
+                .iter()
+                .enumerate()
+                .filter(|(_, &sigma)| sigma > tolerance)
+                .fold(T::zero(), |sum, (k, &sigma)| sum + self.v[i][k] * self.u[j][k].conj() / T::from_real(sigma))
+        })
+    }
+
+    /// An orthonormal basis of the null space: the right singular vectors
+    /// whose singular values are at most `tolerance`, including those beyond
+    /// `min(M, N)`.
+    pub fn null_space(&self, tolerance: T::Real) -> impl Iterator<Item = [T; N]> + '_ {
+        (self.rank(tolerance)..N).map(|k| core::array::from_fn(|i| self.v[i][k]))
+    }
+
+    /// An orthonormal basis of the column space: the left singular vectors
+    /// whose singular values are greater than `tolerance`.
+    pub fn column_space(&self, tolerance: T::Real) -> impl Iterator<Item = [T; M]> + '_ {
+        (0..self.rank(tolerance)).map(|k| core::array::from_fn(|i| self.u[i][k]))
+    }
+}
+
+/// Pseudo-Inverse
+///
+/// The Moore-Penrose pseudo-inverse of a matrix, which solves least-squares
+/// problems for matrices of any shape and rank. See [`Svd::pseudo_inverse`].
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::pseudo_inverse;
+///
+/// assert_eq!(pseudo_inverse(&[[2.0, 0.0]]), Ok([[0.5], [0.0]]));
+/// # }
+/// ```
+///
+/// # Type Parameters
+///
+/// - `M`: The number of rows in the matrix.
+/// - `N`: The number of columns in the matrix.
+///
+/// # Arguments
+///
+/// - `matrix`: The matrix to invert.
+///
+/// # Errors
+///
+/// Returns [`VectorError::NoConvergence`] if the SVD does not converge.
+///
+/// # Returns
+///
+/// A new `N` by `M` matrix containing the pseudo-inverse.
+pub fn pseudo_inverse<const M: usize, const N: usize, T: ComplexField>(matrix: &[[T; N]; M]) -> Result<[[T; M]; N], VectorError> {
+    Ok(Svd::new(matrix)?.pseudo_inverse())
+}
+
+/// Orthogonalize the columns of the `r` by `c` row-major matrix `w`, with
+/// `r >= c`, by rotating pairs of them, accumulating the rotations into `v`,
+/// which must start as the `c` by `c` identity. Then write the column norms
+/// to `sigma` in descending order and the normalized columns to the first
+/// columns of the `r` by `r` matrix `u`, completing it to a unitary matrix.
+/// `w` is divided by its largest element first and the column norms and
+/// cosines are computed with scaling, so neither overflows nor underflows.
+#[allow(clippy::too_many_arguments)]
+fn one_sided_jacobi<T: ComplexField>(
+    w: &mut [T],
+    u: &mut [T],
+    v: &mut [T],
+    sigma: &mut [T::Real],
+    r: usize,
+    c: usize,
+    tolerance: T::Real,
+    max_sweeps: usize,
+) -> Result<(), VectorError> {
+    let largest = w.iter().fold(T::Real::zero(), |max, value| max.max(value.modulus()));
+    let scale = if largest.is_zero() || !largest.is_finite() { T::Real::one() } else { largest };
+    for value in w.iter_mut() {
+        *value /= T::from_real(scale);
+    }
+    let column = |w: &[T], j: usize| scaled_norm(w.chunks_exact(c).map(move |row| row[j]));
+    let mut sweeps = 0;
+    loop {
+        let mut rotated = false;
+        for p in 0..c {
+            for q in p + 1..c {
+                let (norm_p, norm_q) = (column(w, p), column(w, q));
+                if norm_p.is_zero() || norm_q.is_zero() {
+                    continue;
+                }
+                let (scale_p, scale_q) = (T::from_real(norm_p), T::from_real(norm_q));
+                let cosine = w.chunks_exact(c).fold(T::zero(), |sum, row| sum + (row[p] / scale_p).conj() * (row[q] / scale_q));
+                let magnitude = cosine.modulus();
+                if magnitude <= tolerance {
+                    continue;
+                }
+                if !magnitude.is_finite() {
+                    return Err(VectorError::NoConvergence { iterations: sweeps });
+                }
+                rotated = true;
+                let two = T::Real::one() + T::Real::one();
+                // (β - α) / 2|γ| for the Gram matrix entries α, β and γ of the
+                // two columns, without squaring their norms. The phase of γ
+                // goes into the rotation, and is a sign for real matrices.
+                let zeta = (norm_q / norm_p - norm_p / norm_q) / (two * magnitude);
+                let t = (zeta.abs() + (zeta * zeta + T::Real::one()).sqrt()).recip();
+                let t = if zeta < T::Real::zero() { -t } else { t };
+                let cos = (t * t + T::Real::one()).sqrt().recip();
+                let phase = cosine / T::from_real(magnitude);
+                let (cos, sin) = (T::from_real(cos), T::from_real(t * cos));
+                for row in w.chunks_exact_mut(c).chain(v.chunks_exact_mut(c)) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = cos * x - sin * phase.conj() * y;
+                    row[q] = sin * phase * x + cos * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+        sweeps += 1;
+        if sweeps == max_sweeps {
+            return Err(VectorError::NoConvergence { iterations: sweeps });
+        }
+    }
+    for (j, value) in sigma.iter_mut().enumerate() {
+        *value = column(w, j);
+    }
+    for i in 0..c {
+        let largest = (i..c).fold(i, |best, j| if sigma[j] > sigma[best] { j } else { best });
+        if largest != i {
+            sigma.swap(i, largest);
+            for row in w.chunks_exact_mut(c).chain(v.chunks_exact_mut(c)) {
+                row.swap(i, largest);
+            }
+        }
+    }
+    let cutoff = sigma.first().copied().unwrap_or(T::Real::zero()) * T::Real::epsilon() * T::Real::from_f64(r as f64);
+    let given = sigma.iter().take_while(|&&value| value > cutoff).count();
+    for (u_row, w_row) in u.chunks_exact_mut(r).zip(w.chunks_exact(c)) {
+        for j in 0..r {
+            u_row[j] = if j < given { w_row[j] / T::from_real(sigma[j]) } else { T::zero() };
+        }
+    }
+    complete_basis(u, r, given);
+    for value in sigma.iter_mut() {
+        *value *= scale;
+    }
+    Ok(())
+}
+
+/// Fill columns `given..r` of the `r` by `r` row-major matrix `u`, whose first
+/// `given` columns are orthonormal, with the standard basis vectors that
+/// remain largest after projecting out the columns before them.
+fn complete_basis<T: ComplexField>(u: &mut [T], r: usize, given: usize) {
+    let half = (T::Real::one() + T::Real::one()).recip();
+    for j in given..r {
+        for k in 0..r {
+            for i in 0..r {
+                u[i * r + j] = if i == k { T::one() } else { T::zero() };
+            }
+            // Project twice so the result is orthogonal to working precision.
+            for _ in 0..2 {
+                for previous in 0..j {
+                    let projection = (0..r).fold(T::zero(), |sum, i| sum + u[i * r + previous].conj() * u[i * r + j]);
+                    for i in 0..r {
+                        let value = u[i * r + previous];
+                        u[i * r + j] -= projection * value;
+                    }
+                }
+            }
+            // Some standard basis vector keeps a component of at least
+            // 1 / sqrt(r) in the complement, so half of that always occurs.
+            let norm = (0..r).fold(T::Real::zero(), |sum, i| sum + u[i * r + j].norm_sqr()).sqrt();
+            if norm * T::Real::from_f64(r as f64).sqrt() > half {
+                for i in 0..r {
+                    u[i * r + j] /= T::from_real(norm);
+                }
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::{identity, matrix_multiply, transpose};
+
+    fn assert_close<const M: usize, const N: usize>(a: &[[f64; N]; M], b: &[[f64; N]; M]) {
+        for (x, y) in a.as_flattened().iter().zip(b.as_flattened()) {
+            assert!((x - y).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+
+    fn assert_decomposes<const M: usize, const N: usize>(matrix: &[[f64; N]; M]) -> Svd<f64, M, N> {
+        let svd = Svd::new(matrix).unwrap();
+        assert_close(&matrix_multiply(&transpose(&svd.u()), &svd.u()), &identity());
+        assert_close(&matrix_multiply(&transpose(&svd.v()), &svd.v()), &identity());
+        let product = matrix_multiply(&matrix_multiply(&svd.u(), &svd.sigma()), &transpose(&svd.v()));
+        assert_close(&product, matrix);
+        assert!(svd.singular_values().windows(2).all(|pair| pair[0] >= pair[1]));
+        svd
+    }
+
+    #[test]
+    fn test_decomposition() {
+        let tall = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let svd = assert_decomposes(&tall);
+        // The squared singular values are the eigenvalues of AᵀA = [[35, 44], [44, 56]].
+        let expected = [(91.0 + 8185.0_f64.sqrt()) / 2.0, (91.0 - 8185.0_f64.sqrt()) / 2.0];
+        for (sigma, expected) in svd.singular_values().iter().zip(expected) {
+            assert!((sigma * sigma - expected).abs() < 1e-10);
+        }
+        let sigma = crate::diagonal(&[svd.singular_values()[0], svd.singular_values()[1]]);
+        assert_close(&matrix_multiply(&matrix_multiply(&svd.thin_u(), &sigma), &transpose(&svd.v())), &tall);
+        let wide = assert_decomposes(&transpose(&tall));
+        assert_close(&wide.thin_v(), &crate::from_fn(|i, j| wide.v()[i][j]));
+        assert_decomposes(&[[0.0; 3]; 2]);
+        assert_decomposes(&[[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_rank_and_spaces() {
+        let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0], [10.0, 11.0, 12.0]];
+        let svd = assert_decomposes(&matrix);
+        let tolerance = svd.tolerance();
+        assert_eq!(svd.rank(tolerance), 2);
+        assert!(svd.condition_number() > 1e14);
+        let null: [[f64; 3]; 1] = [svd.null_space(tolerance).next().unwrap()];
+        assert_eq!(svd.null_space(tolerance).count(), 1);
+        assert_close(&[crate::matrix_vec_multiply(&matrix, &null[0])], &[[0.0; 4]]);
+        let sixth = 1.0 / 6.0_f64.sqrt();
+        assert!((null[0][0].abs() - sixth).abs() < 1e-10);
+        let columns: [[f64; 4]; 2] = {
+            let mut basis = svd.column_space(tolerance);
+            [basis.next().unwrap(), basis.next().unwrap()]
+        };
+        assert!((crate::dot(&columns[0], &columns[1])).abs() < 1e-12);
+        assert_eq!(svd.column_space(tolerance).count(), 2);
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let tall = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let pinv = pseudo_inverse(&tall).unwrap();
+        assert_close(&matrix_multiply(&pinv, &tall), &identity());
+        // The least-squares solution matches QR.
+        let b = [1.0, 0.0, -1.0];
+        let (expected, _) = crate::lstsq(&tall, &b).unwrap();
+        assert_close(&[crate::matrix_vec_multiply(&pinv, &b)], &[expected]);
+        let singular = [[1.0, 2.0], [2.0, 4.0]];
+        let pinv = pseudo_inverse(&singular).unwrap();
+        assert_close(&pinv, &[[0.04, 0.08], [0.08, 0.16]]);
+        let svd = Svd::new(&[[3.0_f64, 0.0], [0.0, 4.0]]).unwrap();
+        assert!((svd.condition_number() - 4.0 / 3.0).abs() < 1e-15);
+        assert_eq!(svd.spectral_norm(), 4.0);
+    }
+
+    #[test]
+    fn test_extreme_magnitudes() {
+        let root = 5.0_f64.sqrt();
+        for scale in [1e160, 1e-170] {
+            let matrix = [[2.0 * scale, scale], [scale, 3.0 * scale]];
+            let svd = Svd::new(&matrix).unwrap();
+            let expected = [(5.0 + root) / 2.0 * scale, (5.0 - root) / 2.0 * scale];
+            for (value, expected) in svd.singular_values().iter().zip(expected) {
+                assert!((value / expected - 1.0).abs() < 1e-12, "{value} != {expected}");
+            }
+            let product = matrix_multiply(&matrix_multiply(&svd.u(), &svd.sigma()), &transpose(&svd.v()));
+            for (x, y) in product.as_flattened().iter().zip(matrix.as_flattened()) {
+                assert!((x - y).abs() < 1e-12 * scale);
+            }
+        }
+        let svd = Svd::new(&[[3e-170_f64, 0.0], [4e-170, 0.0]]).unwrap();
+        assert!((svd.singular_values()[0] / 5e-170 - 1.0).abs() < 1e-15);
+        assert_eq!(svd.singular_values()[1], 0.0);
+        let svd = Svd::new(&[[1.0, 0.0], [0.0, 1e-200]]).unwrap();
+        assert_eq!(svd.singular_values(), &[1.0, 1e-200]);
+    }
+
+    #[test]
+    fn test_complex() {
+        use crate::{conjugate_transpose, Complex};
+        let c = Complex::new;
+        fn assert_unitary<const N: usize>(matrix: &[[Complex<f64>; N]; N]) {
+            for (x, y) in matrix_multiply(&conjugate_transpose(matrix), matrix).as_flattened().iter().zip(identity::<N, Complex<f64>>().as_flattened()) {
+                assert!((*x - *y).modulus() < 1e-12);
+            }
+        }
+        // AᴴA = [[1, i], [-i, 2]], whose eigenvalues are the squares of the
+        // golden ratio φ and of 1 / φ.
+        let matrix = [[c(1.0, 0.0), c(0.0, 1.0)], [c(0.0, 0.0), c(1.0, 0.0)]];
+        let svd = Svd::new(&matrix).unwrap();
+        let golden = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        for (value, expected) in svd.singular_values().iter().zip([golden, golden - 1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let pinv = svd.pseudo_inverse();
+        for (x, y) in matrix_multiply(&pinv, &matrix).as_flattened().iter().zip(identity::<2, Complex<f64>>().as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        let wide = [[c(1.0, 2.0), c(0.0, -1.0), c(3.0, 0.0)], [c(0.0, 1.0), c(2.0, 2.0), c(-1.0, 1.0)]];
+        let svd = Svd::new(&wide).unwrap();
+        assert_unitary(&svd.u());
+        assert_unitary(&svd.v());
+        let product = matrix_multiply(&matrix_multiply(&svd.u(), &svd.sigma()), &conjugate_transpose(&svd.v()));
+        for (x, y) in product.as_flattened().iter().zip(wide.as_flattened()) {
+            assert!((*x - *y).modulus() < 1e-12);
+        }
+        let null: [Complex<f64>; 3] = svd.null_space(svd.tolerance()).next().unwrap();
+        for value in crate::matrix_vec_multiply(&wide, &null) {
+            assert!(value.modulus() < 1e-12);
+        }
+    }
+}
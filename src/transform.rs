@@ -0,0 +1,613 @@
+use crate::{cross, dot, identity, matrix_vec_multiply, sub, try_normalize, Field, Quaternion, RealField, Ring, VectorError};
+
+/// 2D Translation
+///
+/// The 3×3 homogeneous matrix that moves points by `offset` and leaves
+/// directions unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{transform_direction_2d, transform_point_2d, translation_2d};
+///
+/// let matrix = translation_2d(&[2.0, -1.0]);
+/// assert_eq!(transform_point_2d(&matrix, &[1.0, 1.0]), [3.0, 0.0]);
+/// assert_eq!(transform_direction_2d(&matrix, &[1.0, 1.0]), [1.0, 1.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `offset`: The distance to move along each axis.
+///
+/// # Returns
+///
+/// A new 3×3 translation matrix.
+pub fn translation_2d<T: Ring>(offset: &[T; 2]) -> [[T; 3]; 3] {
+    let mut result = identity();
+    result[0][2] = offset[0];
+    result[1][2] = offset[1];
+    result
+}
+
+/// 3D Translation
+///
+/// The 4×4 homogeneous matrix that moves points by `offset` and leaves
+/// directions unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{matrix_vec_multiply, translation_3d};
+///
+/// let matrix = translation_3d(&[1, 2, 3]);
+/// assert_eq!(matrix_vec_multiply(&matrix, &[1, 1, 1, 1]), [2, 3, 4, 1]);
+/// ```
+///
+/// # Arguments
+///
+/// - `offset`: The distance to move along each axis.
+///
+/// # Returns
+///
+/// A new 4×4 translation matrix.
+pub fn translation_3d<T: Ring>(offset: &[T; 3]) -> [[T; 4]; 4] {
+    let mut result = identity();
+    for (row, value) in result.iter_mut().zip(offset) {
+        row[3] = *value;
+    }
+    result
+}
+
+/// 2D Rotation
+///
+/// The 3×3 homogeneous matrix that rotates counterclockwise about the
+/// origin.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{rotation_2d, transform_point_2d};
+///
+/// let point = transform_point_2d(&rotation_2d(core::f64::consts::FRAC_PI_2), &[1.0, 0.0]);
+/// assert!(point[0].abs() < 1e-15 && (point[1] - 1.0).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `angle`: The angle of rotation in radians.
+///
+/// # Returns
+///
+/// A new 3×3 rotation matrix.
+pub fn rotation_2d<T: RealField>(angle: T) -> [[T; 3]; 3] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let zero = T::zero();
+    [[cos, -sin, zero], [sin, cos, zero], [zero, zero, T::one()]]
+}
+
+/// Rotation About the X Axis
+///
+/// The 4×4 homogeneous matrix that rotates counterclockwise about the `x`
+/// axis when looking down it towards the origin, taking `y` towards `z`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{rotation_x, transform_direction_3d};
+///
+/// let direction = transform_direction_3d(&rotation_x(core::f64::consts::FRAC_PI_2), &[0.0, 1.0, 0.0]);
+/// assert!(direction[1].abs() < 1e-15 && (direction[2] - 1.0).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `angle`: The angle of rotation in radians.
+///
+/// # Returns
+///
+/// A new 4×4 rotation matrix.
+pub fn rotation_x<T: RealField>(angle: T) -> [[T; 4]; 4] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    embed([[one, zero, zero], [zero, cos, -sin], [zero, sin, cos]])
+}
+
+/// Rotation About the Y Axis
+///
+/// The 4×4 homogeneous matrix that rotates counterclockwise about the `y`
+/// axis when looking down it towards the origin, taking `z` towards `x`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{rotation_y, transform_direction_3d};
+///
+/// let direction = transform_direction_3d(&rotation_y(core::f64::consts::FRAC_PI_2), &[0.0, 0.0, 1.0]);
+/// assert!((direction[0] - 1.0).abs() < 1e-15 && direction[2].abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `angle`: The angle of rotation in radians.
+///
+/// # Returns
+///
+/// A new 4×4 rotation matrix.
+pub fn rotation_y<T: RealField>(angle: T) -> [[T; 4]; 4] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    embed([[cos, zero, sin], [zero, one, zero], [-sin, zero, cos]])
+}
+
+/// Rotation About the Z Axis
+///
+/// The 4×4 homogeneous matrix that rotates counterclockwise about the `z`
+/// axis when looking down it towards the origin, taking `x` towards `y`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{rotation_z, transform_direction_3d};
+///
+/// let direction = transform_direction_3d(&rotation_z(core::f64::consts::FRAC_PI_2), &[1.0, 0.0, 0.0]);
+/// assert!(direction[0].abs() < 1e-15 && (direction[1] - 1.0).abs() < 1e-15);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `angle`: The angle of rotation in radians.
+///
+/// # Returns
+///
+/// A new 4×4 rotation matrix.
+pub fn rotation_z<T: RealField>(angle: T) -> [[T; 4]; 4] {
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let (zero, one) = (T::zero(), T::one());
+    embed([[cos, -sin, zero], [sin, cos, zero], [zero, zero, one]])
+}
+
+/// Rotation About an Arbitrary Axis
+///
+/// The 4×4 homogeneous matrix that rotates counterclockwise about `axis`
+/// through the origin when looking down it towards the origin.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{rotation_3d, transform_direction_3d, VectorError};
+///
+/// // A third of a turn about the diagonal cycles the axes.
+/// let matrix = rotation_3d(&[1.0, 1.0, 1.0], 2.0 * core::f64::consts::PI / 3.0).unwrap();
+/// let direction = transform_direction_3d(&matrix, &[1.0, 0.0, 0.0]);
+/// assert!(direction[0].abs() < 1e-15 && (direction[1] - 1.0).abs() < 1e-15);
+/// assert_eq!(rotation_3d(&[0.0; 3], 1.0), Err(VectorError::ZeroVector));
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `axis`: The axis of rotation, which does not need to have unit length.
+/// - `angle`: The angle of rotation in radians.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if the axis is the zero vector.
+///
+/// # Returns
+///
+/// A new 4×4 rotation matrix.
+pub fn rotation_3d<T: RealField>(axis: &[T; 3], angle: T) -> Result<[[T; 4]; 4], VectorError> {
+    Ok(embed(Quaternion::from_axis_angle(axis, angle)?.to_rotation_matrix()))
+}
+
+/// 2D Scaling
+///
+/// The 3×3 homogeneous matrix that scales each axis independently about the
+/// origin.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{scaling_2d, transform_point_2d};
+///
+/// assert_eq!(transform_point_2d(&scaling_2d(&[2.0, 0.5]), &[3.0, 4.0]), [6.0, 2.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `factors`: The scale factor along each axis.
+///
+/// # Returns
+///
+/// A new 3×3 scaling matrix.
+pub fn scaling_2d<T: Ring>(factors: &[T; 2]) -> [[T; 3]; 3] {
+    let mut result = identity();
+    result[0][0] = factors[0];
+    result[1][1] = factors[1];
+    result
+}
+
+/// 3D Scaling
+///
+/// The 4×4 homogeneous matrix that scales each axis independently about the
+/// origin.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{scaling_3d, transform_point_3d};
+///
+/// assert_eq!(transform_point_3d(&scaling_3d(&[2.0, 3.0, -1.0]), &[1.0, 1.0, 1.0]), [2.0, 3.0, -1.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `factors`: The scale factor along each axis.
+///
+/// # Returns
+///
+/// A new 4×4 scaling matrix.
+pub fn scaling_3d<T: Ring>(factors: &[T; 3]) -> [[T; 4]; 4] {
+    let mut result = identity();
+    for (i, factor) in factors.iter().enumerate() {
+        result[i][i] = *factor;
+    }
+    result
+}
+
+/// 2D Shear
+///
+/// The 3×3 homogeneous matrix that adds `x_by_y` times `y` to `x` and
+/// `y_by_x` times `x` to `y`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{shear_2d, transform_point_2d};
+///
+/// assert_eq!(transform_point_2d(&shear_2d(2.0, 0.0), &[1.0, 3.0]), [7.0, 3.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `x_by_y`: How much `x` moves per unit of `y`.
+/// - `y_by_x`: How much `y` moves per unit of `x`.
+///
+/// # Returns
+///
+/// A new 3×3 shear matrix.
+pub fn shear_2d<T: Ring>(x_by_y: T, y_by_x: T) -> [[T; 3]; 3] {
+    let mut result = identity();
+    result[0][1] = x_by_y;
+    result[1][0] = y_by_x;
+    result
+}
+
+/// 3D Shear
+///
+/// The 4×4 homogeneous matrix that adds a multiple of each coordinate to the
+/// other two. Element `factors[i][j]` is how much axis `i` moves per unit of
+/// axis `j`, so `factors[i][i]` is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{shear_3d, transform_point_3d};
+///
+/// // Slide x along z.
+/// let matrix = shear_3d(&[[0.0, 0.0, 0.5], [0.0; 3], [0.0; 3]]);
+/// assert_eq!(transform_point_3d(&matrix, &[1.0, 2.0, 4.0]), [3.0, 2.0, 4.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `factors`: The shear factors, with the diagonal ignored.
+///
+/// # Returns
+///
+/// A new 4×4 shear matrix.
+pub fn shear_3d<T: Ring>(factors: &[[T; 3]; 3]) -> [[T; 4]; 4] {
+    embed(core::array::from_fn(|i| core::array::from_fn(|j| if i == j { T::one() } else { factors[i][j] })))
+}
+
+/// View Matrix
+///
+/// The 4×4 matrix that moves the camera at `eye` to the origin looking down
+/// the negative `z` axis towards `target`, with `up` pointing along positive
+/// `y` as nearly as possible. This is the right-handed convention of
+/// OpenGL's `gluLookAt`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{look_at, transform_point_3d};
+///
+/// let view = look_at(&[0.0, 0.0, 5.0], &[0.0, 0.0, 0.0], &[0.0, 1.0, 0.0]).unwrap();
+/// assert_eq!(transform_point_3d(&view, &[0.0, 0.0, 0.0]), [0.0, 0.0, -5.0]);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `eye`: The position of the camera.
+/// - `target`: The point the camera looks at.
+/// - `up`: The approximate up direction of the camera.
+///
+/// # Errors
+///
+/// Returns [`VectorError::ZeroVector`] if `eye` and `target` coincide or
+/// `up` is parallel to the view direction.
+///
+/// # Returns
+///
+/// A new 4×4 view matrix.
+pub fn look_at<T: RealField>(eye: &[T; 3], target: &[T; 3], up: &[T; 3]) -> Result<[[T; 4]; 4], VectorError> {
+    let forward = try_normalize(&sub(target, eye))?;
+    let side = try_normalize(&cross(&forward, up))?;
+    let up = cross(&side, &forward);
+    let back = forward.map(|value| -value);
+    let row = |axis: [T; 3]| [axis[0], axis[1], axis[2], -dot(&axis, eye)];
+    let (zero, one) = (T::zero(), T::one());
+    Ok([row(side), row(up), row(back), [zero, zero, zero, one]])
+}
+
+/// Perspective Projection
+///
+/// The 4×4 matrix that projects the view frustum with a vertical field of
+/// view `fov_y` onto clip space, following the OpenGL convention: the camera
+/// looks down the negative `z` axis and depths between `near` and `far` map
+/// to `-1` and `1` after dividing by `w`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(any(feature = "std", feature = "libm"))] {
+/// use vector_operations::{perspective, transform_point_3d};
+///
+/// let projection = perspective(core::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+/// let near = transform_point_3d(&projection, &[0.0, 0.0, -1.0]);
+/// let far = transform_point_3d(&projection, &[0.0, 0.0, -10.0]);
+/// assert!((near[2] + 1.0).abs() < 1e-12 && (far[2] - 1.0).abs() < 1e-12);
+/// # }
+/// ```
+///
+/// # Arguments
+///
+/// - `fov_y`: The vertical field of view in radians.
+/// - `aspect`: The width of the view divided by its height.
+/// - `near`: The distance to the near clipping plane, which must be positive.
+/// - `far`: The distance to the far clipping plane.
+///
+/// # Returns
+///
+/// A new 4×4 projection matrix.
+pub fn perspective<T: RealField>(fov_y: T, aspect: T, near: T, far: T) -> [[T; 4]; 4] {
+    let focal = (fov_y * T::from_f64(0.5)).tan().recip();
+    let depth = (near - far).recip();
+    let (zero, one) = (T::zero(), T::one());
+    [
+        [focal / aspect, zero, zero, zero],
+        [zero, focal, zero, zero],
+        [zero, zero, (far + near) * depth, (far * near + far * near) * depth],
+        [zero, zero, -one, zero],
+    ]
+}
+
+/// Orthographic Projection
+///
+/// The 4×4 matrix that maps the box between the given planes onto the cube
+/// from `-1` to `1`, following the same OpenGL convention as
+/// [`perspective`]: `near` and `far` are distances along the negative `z`
+/// axis.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{orthographic, transform_point_3d};
+///
+/// let projection = orthographic(0.0, 4.0, 0.0, 2.0, 1.0, 3.0);
+/// assert_eq!(transform_point_3d(&projection, &[4.0, 1.0, -1.0]), [1.0, 0.0, -1.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `left`, `right`: The `x` coordinates of the sides of the box.
+/// - `bottom`, `top`: The `y` coordinates of the bottom and top.
+/// - `near`, `far`: The distances to the near and far clipping planes.
+///
+/// # Returns
+///
+/// A new 4×4 projection matrix.
+pub fn orthographic<T: Field>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> [[T; 4]; 4] {
+    let two = T::one() + T::one();
+    let (zero, one) = (T::zero(), T::one());
+    let (width, height, depth) = (right - left, top - bottom, far - near);
+    [
+        [two / width, zero, zero, -(right + left) / width],
+        [zero, two / height, zero, -(top + bottom) / height],
+        [zero, zero, -two / depth, -(far + near) / depth],
+        [zero, zero, zero, one],
+    ]
+}
+
+/// Transform a 2D Point
+///
+/// Apply a 3×3 homogeneous matrix to a point, which is affected by
+/// translation. The point is extended with `w = 1` and the result is divided
+/// by its `w`, which is one for every affine transform.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{transform_point_2d, translation_2d};
+///
+/// assert_eq!(transform_point_2d(&translation_2d(&[1.0, 2.0]), &[0.0, 0.0]), [1.0, 2.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The transform.
+/// - `point`: The point to transform.
+///
+/// # Returns
+///
+/// A new point containing the transformed coordinates.
+pub fn transform_point_2d<T: Field>(matrix: &[[T; 3]; 3], point: &[T; 2]) -> [T; 2] {
+    let [x, y, w] = matrix_vec_multiply(matrix, &[point[0], point[1], T::one()]);
+    [x / w, y / w]
+}
+
+/// Transform a 3D Point
+///
+/// Apply a 4×4 homogeneous matrix to a point, which is affected by
+/// translation. The point is extended with `w = 1` and the result is divided
+/// by its `w`, which performs the perspective divide for projections.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{transform_point_3d, translation_3d};
+///
+/// assert_eq!(transform_point_3d(&translation_3d(&[1.0, 2.0, 3.0]), &[1.0, 1.0, 1.0]), [2.0, 3.0, 4.0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The transform.
+/// - `point`: The point to transform.
+///
+/// # Returns
+///
+/// A new point containing the transformed coordinates.
+pub fn transform_point_3d<T: Field>(matrix: &[[T; 4]; 4], point: &[T; 3]) -> [T; 3] {
+    let [x, y, z, w] = matrix_vec_multiply(matrix, &[point[0], point[1], point[2], T::one()]);
+    [x / w, y / w, z / w]
+}
+
+/// Transform a 2D Direction
+///
+/// Apply a 3×3 homogeneous matrix to a direction, which is not affected by
+/// translation. The direction is extended with `w = 0`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{transform_direction_2d, translation_2d};
+///
+/// assert_eq!(transform_direction_2d(&translation_2d(&[1, 2]), &[3, 4]), [3, 4]);
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The transform.
+/// - `direction`: The direction to transform.
+///
+/// # Returns
+///
+/// A new vector containing the transformed direction.
+pub fn transform_direction_2d<T: Ring>(matrix: &[[T; 3]; 3], direction: &[T; 2]) -> [T; 2] {
+    let [x, y, _] = matrix_vec_multiply(matrix, &[direction[0], direction[1], T::zero()]);
+    [x, y]
+}
+
+/// Transform a 3D Direction
+///
+/// Apply a 4×4 homogeneous matrix to a direction, which is not affected by
+/// translation. The direction is extended with `w = 0`.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::{transform_direction_3d, translation_3d};
+///
+/// assert_eq!(transform_direction_3d(&translation_3d(&[1, 2, 3]), &[1, 0, 0]), [1, 0, 0]);
+/// ```
+///
+/// # Arguments
+///
+/// - `matrix`: The transform.
+/// - `direction`: The direction to transform.
+///
+/// # Returns
+///
+/// A new vector containing the transformed direction.
+pub fn transform_direction_3d<T: Ring>(matrix: &[[T; 4]; 4], direction: &[T; 3]) -> [T; 3] {
+    let [x, y, z, _] = matrix_vec_multiply(matrix, &[direction[0], direction[1], direction[2], T::zero()]);
+    [x, y, z]
+}
+
+/// Extend the 3×3 linear transform `matrix` to a 4×4 homogeneous matrix.
+fn embed<T: Ring>(matrix: [[T; 3]; 3]) -> [[T; 4]; 4] {
+    let mut result = identity();
+    for (row, linear) in result.iter_mut().zip(matrix) {
+        row[..3].copy_from_slice(&linear);
+    }
+    result
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+    use crate::matrix_multiply;
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_composition() {
+        // Scale, then rotate a quarter turn, then translate.
+        let matrix = matrix_multiply(&translation_2d(&[1.0, 1.0]), &matrix_multiply(&rotation_2d(FRAC_PI_2), &scaling_2d(&[2.0, 3.0])));
+        assert_close(&transform_point_2d(&matrix, &[1.0, 1.0]), &[-2.0, 3.0]);
+        assert_close(&transform_direction_2d(&matrix, &[1.0, 1.0]), &[-3.0, 2.0]);
+        let matrix = matrix_multiply(&translation_3d(&[0.0, 0.0, 1.0]), &rotation_z(FRAC_PI_2));
+        assert_close(&transform_point_3d(&matrix, &[1.0, 0.0, 0.0]), &[0.0, 1.0, 1.0]);
+        assert_close(&transform_direction_3d(&matrix, &[1.0, 0.0, 0.0]), &[0.0, 1.0, 0.0]);
+        assert_eq!(transform_point_2d(&shear_2d(0.0, 0.5), &[2.0, 1.0]), [2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_axis_rotations() {
+        let axes = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let builders = [rotation_x, rotation_y, rotation_z];
+        for (axis, builder) in axes.iter().zip(builders) {
+            let expected = rotation_3d(axis, 0.7).unwrap();
+            assert_close(builder(0.7).as_flattened(), expected.as_flattened());
+        }
+        let matrix = rotation_3d(&[0.0, 0.0, -2.0], FRAC_PI_4).unwrap();
+        let expected = rotation_z(-FRAC_PI_4);
+        assert_close(matrix.as_flattened(), expected.as_flattened());
+    }
+
+    #[test]
+    fn test_camera() {
+        let view = look_at(&[1.0, 2.0, 3.0], &[1.0, 2.0, 0.0], &[0.0, 1.0, 0.0]).unwrap();
+        assert_close(&transform_point_3d(&view, &[1.0, 2.0, 3.0]), &[0.0, 0.0, 0.0]);
+        assert_close(&transform_point_3d(&view, &[2.0, 3.0, 0.0]), &[1.0, 1.0, -3.0]);
+        let view = look_at(&[0.0, 0.0, 0.0], &[1.0, 0.0, 0.0], &[0.0, 0.0, 1.0]).unwrap();
+        assert_close(&transform_direction_3d(&view, &[1.0, 0.0, 0.0]), &[0.0, 0.0, -1.0]);
+        assert_close(&transform_direction_3d(&view, &[0.0, 0.0, 1.0]), &[0.0, 1.0, 0.0]);
+        assert_eq!(look_at(&[1.0; 3], &[1.0; 3], &[0.0, 1.0, 0.0]), Err(VectorError::ZeroVector));
+        assert_eq!(look_at(&[0.0; 3], &[0.0, 2.0, 0.0], &[0.0, 1.0, 0.0]), Err(VectorError::ZeroVector));
+        // A point on the edge of a 90 degree frustum lands on the edge of clip space.
+        let projection = perspective(FRAC_PI_2, 2.0, 1.0, 100.0);
+        let corner = transform_point_3d(&projection, &[4.0, 2.0, -2.0]);
+        assert_close(&[corner[0], corner[1]], &[1.0, 1.0]);
+        let projection = orthographic(-1.0, 1.0, -2.0, 2.0, 0.5, 2.5);
+        assert_close(&transform_point_3d(&projection, &[-1.0, 2.0, -2.5]), &[-1.0, 1.0, 1.0]);
+        assert_close(&transform_point_3d(&projection, &[0.0, 0.0, -0.5]), &[0.0, 0.0, -1.0]);
+    }
+}
@@ -0,0 +1,220 @@
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{add, scale, sub, Ring};
+
+/// Fixed Size Vector
+///
+/// A vector of `N` elements that supports arithmetic through the standard
+/// operators. Every operator delegates to the free function of the same
+/// name, so `a - b + c * k` computes exactly what
+/// `add(&sub(&a, &b), &scale(&c, &k))` does.
+///
+/// # Examples
+///
+/// ```
+/// use vector_operations::Vector;
+///
+/// let a = Vector::new([1, 2, 3]);
+/// let b = Vector::new([3, 2, 1]);
+/// let c = Vector::new([1, 1, 1]);
+/// assert_eq!(a - b + c * 2, Vector::new([0, 2, 4]));
+/// ```
+///
+/// # Type Parameters
+///
+/// - `T`: The element type.
+/// - `N`: The length of the vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Vector<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Create a vector from an array of elements.
+    pub const fn new(elements: [T; N]) -> Self {
+        Self(elements)
+    }
+
+    /// Borrow the underlying array.
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Mutably borrow the underlying array.
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+
+    /// Consume the vector and return the underlying array.
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+
+    /// The number of elements in the vector.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Whether the vector has no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Iterate over the elements of the vector.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Self([T::default(); N])
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(elements: [T; N]) -> Self {
+        Self(elements)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T; N]> for Vector<T, N> {
+    fn as_ref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T; N]> for Vector<T, N> {
+    fn as_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Vector<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Vector<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Ring, const N: usize> Add for Vector<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(add(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Ring, const N: usize> AddAssign for Vector<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = add(&self.0, &rhs.0);
+    }
+}
+
+impl<T: Ring, const N: usize> Sub for Vector<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(sub(&self.0, &rhs.0))
+    }
+}
+
+impl<T: Ring, const N: usize> SubAssign for Vector<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = sub(&self.0, &rhs.0);
+    }
+}
+
+impl<T: Ring, const N: usize> Mul<T> for Vector<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self(scale(&self.0, &rhs))
+    }
+}
+
+impl<T: Ring, const N: usize> MulAssign<T> for Vector<T, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 = scale(&self.0, &rhs);
+    }
+}
+
+impl<T: Ring + Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|a| -a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_conversions() {
+        let a = Vector::from([1, 2, 3]);
+        assert_eq!(a.as_array(), &[1, 2, 3]);
+        assert_eq!(a.len(), 3);
+        let array: [i32; 3] = a.into();
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_vector_operators() {
+        let a = Vector::new([1, 2, 3]);
+        let b = Vector::new([3, 2, 1]);
+        let c = Vector::new([1, 1, 1]);
+        assert_eq!(a - b + c * 2, Vector::new([0, 2, 4]));
+        assert_eq!(-a, Vector::new([-1, -2, -3]));
+    }
+
+    #[test]
+    fn test_vector_assign_operators() {
+        let mut a = Vector::new([1.0, 2.0]);
+        a += Vector::new([1.0, 1.0]);
+        assert_eq!(a, Vector::new([2.0, 3.0]));
+        a -= Vector::new([0.5, 0.5]);
+        assert_eq!(a, Vector::new([1.5, 2.5]));
+        a *= 2.0;
+        assert_eq!(a, Vector::new([3.0, 5.0]));
+    }
+
+    #[test]
+    fn test_vector_index() {
+        let mut a = Vector::new([1, 2, 3]);
+        a[1] = 5;
+        assert_eq!(a[1], 5);
+        assert_eq!(a.iter().sum::<i32>(), 9);
+    }
+}